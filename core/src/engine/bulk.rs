@@ -1,8 +1,25 @@
-//! Bulk column actions: FindReplace and RegexReplace.
+//! Bulk column actions: FindReplace, RegexReplace, and Operations (a composable op pipeline).
 //! Uses copy-on-write via patches; only changed cells allocate.
 
-use regex::Regex;
+use regex::{Regex, RegexBuilder};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::engine::dataframe::DataFrame;
+use crate::engine::select::ColumnSelector;
+
+/// Default `RegexBuilder::size_limit` (compiled program size) for a user-supplied pattern, so a
+/// catastrophic alternation can't blow up compile memory in the WASM tab. Overridable per action.
+const DEFAULT_SIZE_LIMIT: usize = 50 * 1024 * 1024; // 50MB
+/// Default `RegexBuilder::dfa_size_limit`, same rationale as `DEFAULT_SIZE_LIMIT`.
+const DEFAULT_DFA_SIZE_LIMIT: usize = 10 * 1024 * 1024; // 10MB
+
+/// qsv-style sentinel: a literal `<NULL>` replacement/replace string means "replace matches with
+/// nothing" without forcing the caller to pass an empty field.
+const NULL_SENTINEL: &str = "<NULL>";
+
+fn resolve_sentinel(s: &str) -> &str {
+    if s == NULL_SENTINEL { "" } else { s }
+}
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum BulkAction {
@@ -13,31 +30,213 @@ pub enum BulkAction {
     RegexReplace {
         pattern: String,
         replacement: String,
+        ignore_case: bool,
+        unicode: bool,
+        /// Overrides `DEFAULT_SIZE_LIMIT` when set.
+        #[serde(default)]
+        size_limit: Option<usize>,
+        /// Overrides `DEFAULT_DFA_SIZE_LIMIT` when set.
+        #[serde(default)]
+        dfa_size_limit: Option<usize>,
+    },
+    /// applydp-style transform pipeline: `ops` are folded left-to-right over the cell value
+    /// ("trim then upper" in one action instead of chaining multiple bulk passes).
+    Operations {
+        ops: Vec<Op>,
     },
 }
 
-/// Apply a bulk action to a single cell value.
+/// Which side [`Op::Pad`] pads on.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// A single step in a [`BulkAction::Operations`] pipeline.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum Op {
+    /// Trim leading and trailing whitespace.
+    Trim,
+    /// Trim leading whitespace only.
+    Ltrim,
+    /// Trim trailing whitespace only.
+    Rtrim,
+    Lower,
+    Upper,
+    /// Collapse every run of whitespace into a single space.
+    Squeeze,
+    /// Replace the value with its character count.
+    Len,
+    Replace { from: String, to: String },
+    Pad { width: usize, char: char, side: Side },
+}
+
+/// Apply one `Op` to `value`. Returns the new value and a substitution count: 1 if the op
+/// changed the value (0 if it was a no-op), except `Replace` which counts actual matches like
+/// `FindReplace` does.
+fn apply_op(value: &str, op: &Op) -> (String, usize) {
+    match op {
+        Op::Trim => {
+            let v = value.trim().to_string();
+            let count = (v != value) as usize;
+            (v, count)
+        }
+        Op::Ltrim => {
+            let v = value.trim_start().to_string();
+            let count = (v != value) as usize;
+            (v, count)
+        }
+        Op::Rtrim => {
+            let v = value.trim_end().to_string();
+            let count = (v != value) as usize;
+            (v, count)
+        }
+        Op::Lower => {
+            let v = value.to_lowercase();
+            let count = (v != value) as usize;
+            (v, count)
+        }
+        Op::Upper => {
+            let v = value.to_uppercase();
+            let count = (v != value) as usize;
+            (v, count)
+        }
+        Op::Squeeze => {
+            let v = squeeze_whitespace(value);
+            let count = (v != value) as usize;
+            (v, count)
+        }
+        Op::Len => {
+            let v = value.chars().count().to_string();
+            let count = (v != value) as usize;
+            (v, count)
+        }
+        Op::Replace { from, to } => {
+            let to = resolve_sentinel(to);
+            let count = if from.is_empty() { 0 } else { value.matches(from.as_str()).count() };
+            (value.replace(from.as_str(), to), count)
+        }
+        Op::Pad { width, char, side } => {
+            let len = value.chars().count();
+            if len >= *width {
+                (value.to_string(), 0)
+            } else {
+                let padding: String = std::iter::repeat(*char).take(width - len).collect();
+                let v = match side {
+                    Side::Left => format!("{}{}", padding, value),
+                    Side::Right => format!("{}{}", value, padding),
+                };
+                (v, 1)
+            }
+        }
+    }
+}
+
+/// Collapse every run of whitespace in `value` into a single space, without trimming the ends.
+fn squeeze_whitespace(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut last_was_space = false;
+    for c in value.chars() {
+        if c.is_whitespace() {
+            if !last_was_space {
+                result.push(' ');
+            }
+            last_was_space = true;
+        } else {
+            result.push(c);
+            last_was_space = false;
+        }
+    }
+    result
+}
+
+/// Apply a bulk action to a single cell value, also returning the number of substitutions made
+/// in this cell, so callers can report a qsv-style "N replacements" total rather than just a
+/// changed-cell count.
 /// For `RegexReplace`, `compiled_regex` must be `Some(regex)` compiled from the action's pattern.
 /// Returns an error only for invalid regex (caller compiles once before the loop).
-pub fn apply_to_cell(
+pub fn apply_to_cell_counted(
     value: &str,
     action: &BulkAction,
     compiled_regex: Option<&Regex>,
-) -> Result<String, regex::Error> {
-    let result = match action {
-        BulkAction::FindReplace { search, replace } => value.replace(search, replace),
+) -> Result<(String, usize), regex::Error> {
+    let (result, count) = match action {
+        BulkAction::FindReplace { search, replace } => {
+            let replace = resolve_sentinel(replace);
+            let count = if search.is_empty() { 0 } else { value.matches(search.as_str()).count() };
+            (value.replace(search.as_str(), replace), count)
+        }
         BulkAction::RegexReplace { replacement, .. } => {
             let re = compiled_regex.expect("RegexReplace requires compiled_regex");
-            re.replace_all(value, replacement.as_str()).into_owned()
+            let replacement = resolve_sentinel(replacement);
+            let count = re.find_iter(value).count();
+            (re.replace_all(value, replacement).into_owned(), count)
+        }
+        BulkAction::Operations { ops } => {
+            let mut current = value.to_string();
+            let mut total = 0;
+            for op in ops {
+                let (next, count) = apply_op(&current, op);
+                total += count;
+                current = next;
+            }
+            (current, total)
         }
     };
-    Ok(result)
+    Ok((result, count))
 }
 
 /// Compile the regex for a RegexReplace action. Call once before iterating rows.
 pub fn compile_regex_for_action(action: &BulkAction) -> Result<Option<Regex>, regex::Error> {
     match action {
         BulkAction::FindReplace { .. } => Ok(None),
-        BulkAction::RegexReplace { pattern, .. } => Regex::new(pattern).map(Some),
+        BulkAction::Operations { .. } => Ok(None),
+        BulkAction::RegexReplace { pattern, ignore_case, unicode, size_limit, dfa_size_limit, .. } => {
+            RegexBuilder::new(pattern)
+                .case_insensitive(*ignore_case)
+                .unicode(*unicode)
+                .size_limit(size_limit.unwrap_or(DEFAULT_SIZE_LIMIT))
+                .dfa_size_limit(dfa_size_limit.unwrap_or(DEFAULT_DFA_SIZE_LIMIT))
+                .build()
+                .map(Some)
+        }
+    }
+}
+
+/// Apply `action` to only the columns `selector` picks out of `df`, compiling the regex once and
+/// writing changed cells as copy-on-write patches, like qsv's `--select`. Returns the number of
+/// substitutions made per touched column (`col_idx -> replacements`); columns with no matches are
+/// omitted.
+pub fn apply_bulk_action(
+    df: &mut DataFrame,
+    action: &BulkAction,
+    selector: &ColumnSelector,
+) -> Result<HashMap<usize, usize>, regex::Error> {
+    let compiled_regex = compile_regex_for_action(action)?;
+    let target_cols = selector.resolve(&df.columns);
+
+    let mut replacements_by_col: HashMap<usize, usize> = HashMap::new();
+    for col_idx in target_cols {
+        let mut prior: Vec<(usize, Option<String>)> = Vec::new();
+        for row_idx in 0..df.rows {
+            if let Some(old_val) = df.get_cell(row_idx, col_idx) {
+                let (new_val, count) = apply_to_cell_counted(&old_val, action, compiled_regex.as_ref())?;
+                if count > 0 {
+                    *replacements_by_col.entry(col_idx).or_insert(0) += count;
+                }
+                if new_val != old_val {
+                    let prior_val = df.patches.get(&row_idx).and_then(|m| m.get(&col_idx)).cloned();
+                    df.patches
+                        .entry(row_idx)
+                        .or_insert_with(HashMap::new)
+                        .insert(col_idx, new_val);
+                    prior.push((row_idx, prior_val));
+                }
+            }
+        }
+        df.record_operation("apply_bulk_action_selected", col_idx, prior);
+        df.invalidate_index(col_idx);
     }
+    Ok(replacements_by_col)
 }
@@ -1,23 +1,68 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
 use serde::Serialize;
 use crate::engine::schema::{ColumnSchema, ColumnType};
+use crate::engine::expr::Expr;
 use std::io::Cursor;
 use csv::StringRecord;
 
+/// Default depth of the undo/redo ring buffer (see [`DataFrame::undo_stack`]): bounds how many
+/// operations a long editing session keeps around, since each entry holds one `Option<String>`
+/// per cell the operation touched and a `find_replace_all` can touch millions of them.
+pub const DEFAULT_HISTORY_DEPTH: usize = 50;
+
+/// One undo-able mutation, scoped to a single column: enough to reverse or replay it without
+/// keeping a full dataset snapshot. `prior` holds `(row_idx, value_before_this_operation)` for
+/// every cell the operation touched; `None` means the cell had no patch before the operation, so
+/// undoing it removes the patch entirely rather than writing a value over it.
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+    pub label: String,
+    pub col_idx: usize,
+    pub prior: Vec<(usize, Option<String>)>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct DataFrame {
     #[serde(skip)]
-    pub raw_data: Vec<u8>,
+    pub raw_data: Arc<Vec<u8>>,
     #[serde(skip)]
     pub row_indices: Vec<usize>, // Start byte of every row
     pub columns: Vec<ColumnSchema>,
     // Map<RowIndex, Map<ColIndex, NewValue>>
     pub patches: HashMap<usize, HashMap<usize, String>>,
     pub rows: usize,
+    /// Rows quarantined during parsing because their field count didn't match the header
+    /// (too few/too many columns), as `(row_idx, raw_line)`. Kept out of `row_indices` entirely
+    /// so they can't throw off column alignment for the rest of the table.
+    pub bad_rows: Vec<(usize, String)>,
+    /// Undo ring buffer, oldest entry at the front. Bounded by `history_depth`.
+    #[serde(skip)]
+    pub undo_stack: VecDeque<HistoryEntry>,
+    /// Redo ring buffer, built from entries popped off `undo_stack`. Cleared whenever a new
+    /// mutation is recorded, same as any standard undo/redo model.
+    #[serde(skip)]
+    pub redo_stack: VecDeque<HistoryEntry>,
+    #[serde(skip)]
+    pub history_depth: usize,
+    /// Cached invalid-row index per column (`col_idx -> sorted invalid row indices`), populated
+    /// lazily by `validate_column_fast`/`validate_range` so `apply_suggestion`/`apply_correction`
+    /// can scan just the broken rows instead of all `rows` of them. Removed for a column whenever
+    /// its patches or `detected_type` change, so a stale entry is never read back; absent simply
+    /// means "not computed yet," not "no errors."
+    #[serde(skip)]
+    pub invalid_index: HashMap<usize, Vec<usize>>,
 }
 
 impl DataFrame {
-    pub fn new(raw_data: Vec<u8>, row_indices: Vec<usize>, columns: Vec<ColumnSchema>) -> Self {
+    pub fn new(raw_data: Vec<u8>, row_indices: Vec<usize>, columns: Vec<ColumnSchema>, bad_rows: Vec<(usize, String)>) -> Self {
+        Self::from_shared(Arc::new(raw_data), row_indices, columns, bad_rows)
+    }
+
+    /// Like [`DataFrame::new`], but takes raw bytes already behind an `Arc` so a batched parser
+    /// can hand out incremental snapshot `DataFrame`s over the same buffer (`Arc::clone`, no
+    /// copy) instead of re-cloning the whole upload once per batch.
+    pub fn from_shared(raw_data: Arc<Vec<u8>>, row_indices: Vec<usize>, columns: Vec<ColumnSchema>, bad_rows: Vec<(usize, String)>) -> Self {
         let rows = row_indices.len();
         DataFrame {
             raw_data,
@@ -25,7 +70,105 @@ impl DataFrame {
             columns,
             patches: HashMap::new(),
             rows,
+            bad_rows,
+            undo_stack: VecDeque::new(),
+            redo_stack: VecDeque::new(),
+            history_depth: DEFAULT_HISTORY_DEPTH,
+            invalid_index: HashMap::new(),
+        }
+    }
+
+    /// Cached invalid row indices for `col_idx`, if a full scan has populated it since the last
+    /// change to that column's patches or type. `None` means "not computed yet" — callers should
+    /// fall back to `validate_column_fast` rather than treat it as "no errors."
+    pub fn invalid_rows(&self, col_idx: usize) -> Option<&Vec<usize>> {
+        self.invalid_index.get(&col_idx)
+    }
+
+    /// Drop the cached invalid-row index for `col_idx`. Call this after any mutation that could
+    /// change which rows are valid for that column (a patch write or a `detected_type` change).
+    pub fn invalidate_index(&mut self, col_idx: usize) {
+        self.invalid_index.remove(&col_idx);
+    }
+
+    /// Override the undo/redo ring buffer depth (default [`DEFAULT_HISTORY_DEPTH`]).
+    pub fn set_history_depth(&mut self, depth: usize) {
+        self.history_depth = depth.max(1);
+        while self.undo_stack.len() > self.history_depth {
+            self.undo_stack.pop_front();
+        }
+        while self.redo_stack.len() > self.history_depth {
+            self.redo_stack.pop_front();
+        }
+    }
+
+    /// Record a completed mutation so it can be undone later. Call this *after* applying the
+    /// patches, with `prior` holding each touched cell's value from *before* the mutation.
+    /// No-ops if `prior` is empty (nothing was actually changed). Clears the redo stack, since a
+    /// fresh mutation invalidates whatever could previously be redone.
+    pub fn record_operation(&mut self, label: impl Into<String>, col_idx: usize, prior: Vec<(usize, Option<String>)>) {
+        if prior.is_empty() {
+            return;
+        }
+        self.undo_stack.push_back(HistoryEntry { label: label.into(), col_idx, prior });
+        if self.undo_stack.len() > self.history_depth {
+            self.undo_stack.pop_front();
+        }
+        self.redo_stack.clear();
+    }
+
+    pub fn history_len(&self) -> usize {
+        self.undo_stack.len()
+    }
+
+    /// Undo the most recent recorded operation, restoring each of its cells to the value it had
+    /// beforehand, and push the inverse onto the redo stack. Returns `false` if there was nothing
+    /// to undo.
+    pub fn undo(&mut self) -> bool {
+        let Some(entry) = self.undo_stack.pop_back() else { return false };
+        let inverse = self.apply_history_prior(&entry);
+        self.redo_stack.push_back(HistoryEntry { label: entry.label, col_idx: entry.col_idx, prior: inverse });
+        if self.redo_stack.len() > self.history_depth {
+            self.redo_stack.pop_front();
+        }
+        true
+    }
+
+    /// Replay the most recently undone operation. Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(entry) = self.redo_stack.pop_back() else { return false };
+        let inverse = self.apply_history_prior(&entry);
+        self.undo_stack.push_back(HistoryEntry { label: entry.label, col_idx: entry.col_idx, prior: inverse });
+        if self.undo_stack.len() > self.history_depth {
+            self.undo_stack.pop_front();
+        }
+        true
+    }
+
+    /// Write `entry.prior` back into `patches` for `entry.col_idx`, returning the values that
+    /// were current immediately beforehand (i.e. the inverse of `entry`, for the opposite stack).
+    /// Invalidates `entry.col_idx`'s cached invalid-row index, same as any other patch write.
+    fn apply_history_prior(&mut self, entry: &HistoryEntry) -> Vec<(usize, Option<String>)> {
+        let mut inverse = Vec::with_capacity(entry.prior.len());
+        for (row_idx, prior_val) in &entry.prior {
+            let current = self.patches.get(row_idx).and_then(|m| m.get(&entry.col_idx)).cloned();
+            inverse.push((*row_idx, current));
+            match prior_val {
+                Some(v) => {
+                    self.patches.entry(*row_idx).or_insert_with(HashMap::new).insert(entry.col_idx, v.clone());
+                }
+                None => {
+                    if let Some(row_patches) = self.patches.get_mut(row_idx) {
+                        row_patches.remove(&entry.col_idx);
+                        if row_patches.is_empty() {
+                            self.patches.remove(row_idx);
+                        }
+                    }
+                }
+            }
         }
+        self.invalidate_index(entry.col_idx);
+        inverse
     }
 
     pub fn get_cell(&self, row_idx: usize, col_idx: usize) -> Option<String> {
@@ -41,34 +184,66 @@ impl DataFrame {
             return None;
         }
 
+        // 2b. Computed column: evaluate its expression instead of reading raw_data, which has no
+        // entry for it at all.
+        if let Some(expr) = &self.columns[col_idx].computed {
+            return Some(expr.eval(self, row_idx));
+        }
+
         // 3. Get from Raw Data (use get to avoid panic on bad index)
         let start = match self.row_indices.get(row_idx) {
             Some(&s) => s,
             None => return None,
         };
 
-        // Slice from 'start' to the end. csv::Reader will read just the first record.
+        self.record_at(start).get(col_idx).map(|s| s.to_string())
+    }
+
+    /// Parse the single CSV record starting at byte offset `start` in `raw_data`. `row_indices`
+    /// only holds good rows' offsets, and `bad_rows`' bytes still sit physically between them in
+    /// `raw_data` (they're quarantined out of `row_indices`, not cut out of the buffer), so every
+    /// raw-data read has to open its own reader at its own row's known offset rather than assume
+    /// the next bytes in the stream belong to the next logical row.
+    fn record_at(&self, start: usize) -> StringRecord {
         let slice = &self.raw_data[start..];
         let cursor = Cursor::new(slice);
         let mut reader = csv::ReaderBuilder::new()
             .has_headers(false)
             .from_reader(cursor);
-        
-        let mut record = StringRecord::new();
-        // read_record returns true if a record was read
-        if reader.read_record(&mut record).unwrap_or(false) {
-             return record.get(col_idx).map(|s| s.to_string());
-        }
 
-        None
+        let mut record = StringRecord::new();
+        reader.read_record(&mut record).unwrap_or(false);
+        record
     }
     
     pub fn set_column_type(&mut self, col_idx: usize, new_type: ColumnType) {
         if col_idx < self.columns.len() {
             self.columns[col_idx].detected_type = new_type;
+            self.invalidate_index(col_idx);
         }
     }
 
+    /// Append a derived column evaluated lazily per row from `expr` (see `engine::expr`), rather
+    /// than materializing the whole column up front. Has no entry in `raw_data`/`row_indices`;
+    /// `get_cell`/`get_row`/`validate_column_fast` each special-case a `computed` column to
+    /// evaluate instead of reading the raw CSV record. Returns the new column's index.
+    ///
+    /// Rejects `expr` if it would reference the new column's own index, directly or through
+    /// another computed column it depends on: `get_cell`/`Expr::eval` call each other with no
+    /// depth guard, so a self-referential computed column would recurse forever (and overflow the
+    /// stack) the moment anything reads it.
+    pub fn add_computed_column(&mut self, name: String, detected_type: ColumnType, expr: Expr) -> Result<usize, String> {
+        let col_idx = self.columns.len();
+        if expr_references(&self.columns, &expr, col_idx, &mut HashSet::new()) {
+            return Err(format!(
+                "Computed column '{}' cannot reference itself, directly or via another computed column",
+                name
+            ));
+        }
+        self.columns.push(ColumnSchema { name, detected_type, computed: Some(expr) });
+        Ok(col_idx)
+    }
+
     pub fn update_cell(&mut self, row_idx: usize, col_idx: usize, value: String) -> Result<(), String> {
         if row_idx >= self.rows {
             return Err(format!("Row index {} out of bounds (max: {})", row_idx, self.rows - 1));
@@ -81,7 +256,8 @@ impl DataFrame {
             .entry(row_idx)
             .or_insert_with(HashMap::new)
             .insert(col_idx, value);
-        
+        self.invalidate_index(col_idx);
+
         Ok(())
     }
 
@@ -97,16 +273,8 @@ impl DataFrame {
         // But for consistency with get_cell, we can also iterate cols.
         // Let's read raw first to avoid N parsings.
         
-        let start = self.row_indices[row_idx];
-        let slice = &self.raw_data[start..];
-        let cursor = Cursor::new(slice);
-        let mut reader = csv::ReaderBuilder::new()
-            .has_headers(false)
-            .from_reader(cursor);
-        
-        let mut record = StringRecord::new();
-        let has_raw = reader.read_record(&mut record).unwrap_or(false);
-        
+        let record = self.record_at(self.row_indices[row_idx]);
+
         for col_idx in 0..self.columns.len() {
             // Check patch
             if let Some(row_patches) = self.patches.get(&row_idx) {
@@ -115,73 +283,87 @@ impl DataFrame {
                     continue;
                 }
             }
-            
-            // Use raw
-            if has_raw {
-                row_values.push(record.get(col_idx).unwrap_or("").to_string());
-            } else {
-                row_values.push("".to_string());
+
+            // Computed column: evaluate instead of reading the raw record, which has no field
+            // for it at all.
+            if let Some(expr) = &self.columns[col_idx].computed {
+                row_values.push(expr.eval(self, row_idx));
+                continue;
             }
+
+            // Use raw
+            row_values.push(record.get(col_idx).unwrap_or("").to_string());
         }
         
         Some(row_values)
     }
 
-    pub fn validate_range(&self, start_row: usize, limit: usize) -> Vec<usize> {
+    pub fn validate_range(&mut self, start_row: usize, limit: usize) -> Vec<usize> {
         let mut errors = Vec::new();
         if start_row >= self.rows {
             return errors;
         }
+        let end_row = std::cmp::min(start_row + limit, self.rows);
 
-        let start_byte = self.row_indices[start_row];
-        let slice = &self.raw_data[start_byte..];
-        let cursor = Cursor::new(slice);
-        let mut reader = csv::ReaderBuilder::new()
-            .has_headers(false)
-            .from_reader(cursor);
-        
-        let mut record = StringRecord::new();
-        
-        for i in 0..limit {
-            let current_row = start_row + i;
-            if current_row >= self.rows {
-                break;
-            }
-            
-            // Read into reusable record
-            if !reader.read_record(&mut record).unwrap_or(false) {
-                break;
-            }
+        // Rows found invalid per column in this range, merged into `invalid_index` below once
+        // the scan (which borrows `raw_data`/`patches` immutably) is done.
+        let mut per_col_invalid: HashMap<usize, Vec<usize>> = HashMap::new();
+
+        // `row_indices` is the logical (good-rows-only) space: a quarantined `bad_rows` entry
+        // between two good rows still occupies bytes in `raw_data`, it's just skipped over by
+        // `row_indices`. So each row has to be parsed starting from its own known offset rather
+        // than assumed to be the next record a single sequential reader would hand back.
+        for current_row in start_row..end_row {
+            let record = self.record_at(self.row_indices[current_row]);
 
             for (col_idx, column) in self.columns.iter().enumerate() {
                 // 1. Check Patch
                 let mut is_patched = false;
+                let mut is_invalid = false;
                 if let Some(row_patches) = self.patches.get(&current_row) {
-                     if let Some(patch_val) = row_patches.get(&col_idx) {
-                         is_patched = true;
-                         if !column.detected_type.is_valid_fast(patch_val) {
-                             errors.push(current_row);
-                             errors.push(col_idx);
-                         }
-                     }
+                    if let Some(patch_val) = row_patches.get(&col_idx) {
+                        is_patched = true;
+                        is_invalid = !column.detected_type.is_valid_fast(patch_val);
+                    }
                 }
 
-                // 2. Check Raw
+                // 2. Computed column: evaluate instead of reading the raw record, which has
+                // no field for it. 3. Otherwise check raw.
                 if !is_patched {
-                    if let Some(val) = record.get(col_idx) {
-                        if !column.detected_type.is_valid_fast(val) {
-                            errors.push(current_row);
-                            errors.push(col_idx);
-                        }
+                    if let Some(expr) = &column.computed {
+                        let val = expr.eval(self, current_row);
+                        is_invalid = !column.detected_type.is_valid_fast(&val);
+                    } else if let Some(val) = record.get(col_idx) {
+                        is_invalid = !column.detected_type.is_valid_fast(val);
                     }
                 }
+
+                if is_invalid {
+                    errors.push(current_row);
+                    errors.push(col_idx);
+                    per_col_invalid.entry(col_idx).or_insert_with(Vec::new).push(current_row);
+                }
+            }
+        }
+
+        // Merge the freshly-scanned range into each column's cached invalid-row index. Columns
+        // with no cached index yet are left alone: a partial index built from just this range
+        // would make `invalid_rows` silently incomplete. It's populated in full the first time
+        // `validate_column_fast` runs for that column.
+        for col_idx in 0..self.columns.len() {
+            let fresh = per_col_invalid.remove(&col_idx).unwrap_or_default();
+            if let Some(existing) = self.invalid_index.get_mut(&col_idx) {
+                existing.retain(|r| *r < start_row || *r >= end_row);
+                existing.extend(fresh);
+                existing.sort_unstable();
             }
         }
+
         errors
     }
 
-    /// Find/replace over a range of rows using one CSV Reader (streaming), like validate_range.
-    /// Returns the number of cells updated.
+    /// Find/replace over a range of rows, like validate_range. Returns the number of cells
+    /// updated.
     pub fn find_replace_range(&mut self, start_row: usize, row_limit: usize, find: &str, replace: &str) -> Result<u32, String> {
         if start_row >= self.rows {
             return Ok(0);
@@ -189,24 +371,13 @@ impl DataFrame {
         let cols = self.columns.len();
         let end_row = std::cmp::min(start_row.saturating_add(row_limit), self.rows);
 
-        let start_byte = match self.row_indices.get(start_row) {
-            Some(&b) => b,
-            None => return Ok(0),
-        };
-        let slice = &self.raw_data[start_byte..];
-        let cursor = Cursor::new(slice);
-        let mut reader = csv::ReaderBuilder::new()
-            .has_headers(false)
-            .from_reader(cursor);
-
-        let mut record = StringRecord::new();
         let mut updates: Vec<(usize, usize, String)> = Vec::new();
 
-        for i in 0..(end_row - start_row) {
-            let current_row = start_row + i;
-            if !reader.read_record(&mut record).unwrap_or(false) {
-                break;
-            }
+        // Parse each row from its own `row_indices` offset (see `record_at`) rather than reading
+        // sequentially: a quarantined `bad_rows` entry between two good rows leaves its bytes in
+        // `raw_data`, so a single sequential reader would misread them as the next logical row.
+        for current_row in start_row..end_row {
+            let record = self.record_at(self.row_indices[current_row]);
 
             for col_idx in 0..cols {
                 let val: String = if let Some(row_patches) = self.patches.get(&current_row) {
@@ -226,60 +397,218 @@ impl DataFrame {
         }
 
         let count = updates.len() as u32;
+        let mut prior_by_col: HashMap<usize, Vec<(usize, Option<String>)>> = HashMap::new();
         for (row, col, val) in updates {
+            let prior_val = self.patches.get(&row).and_then(|m| m.get(&col)).cloned();
             self.update_cell(row, col, val)?;
+            prior_by_col.entry(col).or_insert_with(Vec::new).push((row, prior_val));
+        }
+        for (col_idx, prior) in prior_by_col {
+            self.record_operation("find_replace_range", col_idx, prior);
         }
         Ok(count)
     }
 
-    pub fn validate_column_fast(&self, col_idx: usize, col_type: ColumnType) -> Vec<usize> {
+    /// Full-column scan for invalid rows under `col_type`, caching the result in `invalid_index`
+    /// so a subsequent `invalid_rows(col_idx)` (or `apply_suggestion`/`apply_correction`) doesn't
+    /// have to repeat it.
+    pub fn validate_column_fast(&mut self, col_idx: usize, col_type: ColumnType) -> Vec<usize> {
+        let error_indices = self.scan_invalid_rows(col_idx, col_type);
+        self.invalid_index.insert(col_idx, error_indices.clone());
+        error_indices
+    }
+
+    /// Walks `row_indices` — the same logical (good-rows-only, quarantined `bad_rows` already
+    /// excluded) space every other part of the engine indexes by — rather than re-parsing
+    /// `raw_data` from scratch with a fresh reader. A raw re-parse's `enumerate()` still counts a
+    /// quarantined row even though `csv` yields `Err` for it, so its row numbers drift from
+    /// `row_indices`/`patches` by however many bad rows precede each row; every caller that reads
+    /// `row_idx` back out of `invalid_index` (`apply_suggestion`, `apply_correction`,
+    /// `get_invalid_rows`, ...) would then patch or report the wrong row.
+    fn scan_invalid_rows(&self, col_idx: usize, col_type: ColumnType) -> Vec<usize> {
         let mut error_indices = Vec::new();
 
-        let cursor = Cursor::new(&self.raw_data);
-        let mut reader = csv::ReaderBuilder::new()
-            .has_headers(true) // Assumes headers are present, adjust if needed
-            .from_reader(cursor);
+        for row_idx in 0..self.rows {
+            let mut is_valid = true;
+            let mut is_patched = false;
 
-        // We iterate with an index to get the row number
-        for (row_idx, result) in reader.records().enumerate() {
-            match result {
-                Ok(record) => {
-                    let mut is_valid = true;
-                    let mut is_patched = false;
-
-                    // 1. Check Patches first
-                    if let Some(row_patches) = self.patches.get(&row_idx) {
-                        if let Some(patch_val) = row_patches.get(&col_idx) {
-                            is_patched = true;
-                            if !col_type.is_valid_fast(patch_val) {
-                                is_valid = false;
-                            }
-                        }
+            // 1. Check Patches first
+            if let Some(row_patches) = self.patches.get(&row_idx) {
+                if let Some(patch_val) = row_patches.get(&col_idx) {
+                    is_patched = true;
+                    if !col_type.is_valid_fast(patch_val) {
+                        is_valid = false;
                     }
+                }
+            }
 
-                    // 2. Check Raw if not patched
-                    if !is_patched {
-                        if let Some(val) = record.get(col_idx) {
+            // 2. Computed column: evaluate instead of reading the raw record, which has
+            // no field for it (it only covers the CSV's own columns).
+            if !is_patched {
+                if let Some(expr) = &self.columns[col_idx].computed {
+                    if !col_type.is_valid_fast(&expr.eval(self, row_idx)) {
+                        is_valid = false;
+                    }
+                } else {
+                    let record = self.record_at(self.row_indices[row_idx]);
+                    match record.get(col_idx) {
+                        Some(val) => {
                             if !col_type.is_valid_fast(val) {
                                 is_valid = false;
                             }
-                        } else {
-                            // This case means the record has fewer columns than col_idx, which is an error
+                        }
+                        None => {
+                            // Fewer columns than col_idx, which is an error.
                             is_valid = false;
                         }
                     }
-
-                    if !is_valid {
-                        error_indices.push(row_idx);
-                    }
-                }
-                Err(_) => {
-                    // This row is malformed, so it's an error.
-                    error_indices.push(row_idx);
                 }
             }
+
+            if !is_valid {
+                error_indices.push(row_idx);
+            }
         }
 
         error_indices
     }
 }
+
+/// Does `expr` reach column `target` through any `Expr::Column` reference, following through any
+/// referenced computed column's own expression (looked up in `columns`)? `visited` guards against
+/// looping forever if the referenced columns somehow already formed a cycle.
+///
+/// Free function rather than a `DataFrame` method so [`validate_computed_columns`] can check a
+/// caller-supplied schema (`update_schema`) against *itself* before it's ever installed as
+/// `DataFrame::columns`.
+fn expr_references(columns: &[ColumnSchema], expr: &Expr, target: usize, visited: &mut HashSet<usize>) -> bool {
+    match expr {
+        Expr::Column { index } => {
+            if *index == target {
+                return true;
+            }
+            if !visited.insert(*index) {
+                return false;
+            }
+            match columns.get(*index).and_then(|c| c.computed.as_ref()) {
+                Some(inner) => expr_references(columns, inner, target, visited),
+                None => false,
+            }
+        }
+        Expr::Literal { .. } => false,
+        Expr::Concat { args } => args.iter().any(|a| expr_references(columns, a, target, visited)),
+        Expr::Substring { value, .. } => expr_references(columns, value, target, visited),
+        Expr::Add { left, right }
+        | Expr::Sub { left, right }
+        | Expr::Mul { left, right }
+        | Expr::Div { left, right }
+        | Expr::Gt { left, right }
+        | Expr::Lt { left, right }
+        | Expr::Eq { left, right } => {
+            expr_references(columns, left, target, visited) || expr_references(columns, right, target, visited)
+        }
+    }
+}
+
+/// Reject `columns` if any computed column's expression reaches back to itself, directly or
+/// through another computed column — the same check `DataFrame::add_computed_column` runs for a
+/// single new column, generalized to every column in `columns`. Needed because `update_schema`
+/// replaces `DataFrame::columns` wholesale from caller-supplied, freely-deserialized JSON, which
+/// bypasses `add_computed_column` (and its cycle guard) entirely: without this, two columns whose
+/// `computed` exprs reference each other would pass straight through, and the first `get_cell` on
+/// either one would recurse forever between `get_cell` and `Expr::eval`.
+pub fn validate_computed_columns(columns: &[ColumnSchema]) -> Result<(), String> {
+    for (idx, column) in columns.iter().enumerate() {
+        if let Some(expr) = &column.computed {
+            if expr_references(columns, expr, idx, &mut HashSet::new()) {
+                return Err(format!(
+                    "Computed column '{}' has a circular reference",
+                    column.name
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_df() -> DataFrame {
+        DataFrame::new(Vec::new(), Vec::new(), Vec::new(), Vec::new())
+    }
+
+    fn plain_column(name: &str) -> ColumnSchema {
+        ColumnSchema { name: name.to_string(), detected_type: ColumnType::Text, computed: None }
+    }
+
+    #[test]
+    fn add_computed_column_rejects_direct_self_reference() {
+        let mut df = empty_df();
+        df.columns.push(plain_column("a"));
+        let col_idx = df.columns.len();
+        let err = df
+            .add_computed_column("self".to_string(), ColumnType::Text, Expr::Column { index: col_idx })
+            .unwrap_err();
+        assert!(err.contains("cannot reference itself"));
+    }
+
+    #[test]
+    fn add_computed_column_rejects_transitive_cycle() {
+        let mut df = empty_df();
+        df.columns.push(plain_column("a"));
+        // Column 1 ("b") is computed as column 2's value; adding column 2 ("c") referencing
+        // column 1 would close the loop b -> c -> b.
+        df.columns.push(ColumnSchema {
+            name: "b".to_string(),
+            detected_type: ColumnType::Text,
+            computed: Some(Expr::Column { index: 2 }),
+        });
+        let err = df
+            .add_computed_column("c".to_string(), ColumnType::Text, Expr::Column { index: 1 })
+            .unwrap_err();
+        assert!(err.contains("cannot reference itself"));
+    }
+
+    #[test]
+    fn add_computed_column_accepts_acyclic_reference() {
+        let mut df = empty_df();
+        df.columns.push(plain_column("a"));
+        let result = df.add_computed_column("b".to_string(), ColumnType::Text, Expr::Column { index: 0 });
+        assert_eq!(result, Ok(1));
+    }
+
+    #[test]
+    fn validate_computed_columns_rejects_mutual_reference_bypassing_add_computed_column() {
+        // The exact shape update_schema must guard against: two computed columns installed in one
+        // shot (bypassing add_computed_column entirely) whose expressions reference each other.
+        let columns = vec![
+            ColumnSchema {
+                name: "a".to_string(),
+                detected_type: ColumnType::Text,
+                computed: Some(Expr::Column { index: 1 }),
+            },
+            ColumnSchema {
+                name: "b".to_string(),
+                detected_type: ColumnType::Text,
+                computed: Some(Expr::Column { index: 0 }),
+            },
+        ];
+        let err = validate_computed_columns(&columns).unwrap_err();
+        assert!(err.contains("circular reference"));
+    }
+
+    #[test]
+    fn validate_computed_columns_accepts_acyclic_schema() {
+        let columns = vec![
+            plain_column("a"),
+            ColumnSchema {
+                name: "b".to_string(),
+                detected_type: ColumnType::Text,
+                computed: Some(Expr::Column { index: 0 }),
+            },
+        ];
+        assert_eq!(validate_computed_columns(&columns), Ok(()));
+    }
+}
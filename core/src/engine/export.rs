@@ -0,0 +1,101 @@
+//! Streaming export of a (patched) `DataFrame` to CSV or ndjson. `get_rows` only returns
+//! windowed slices for the virtual table, so this is the only path that lets a caller get the
+//! patched dataset back out. Output is handed to the caller in byte chunks rather than built up
+//! as one giant buffer, so a large export doesn't have to be fully resident before anything is
+//! written.
+
+use crate::engine::dataframe::DataFrame;
+use std::io::Write;
+
+/// Byte-size threshold at which `export_rows` flushes its buffer to the caller's `chunk`
+/// callback, so a multi-hundred-MB export doesn't have to be built up in memory first.
+pub const DEFAULT_CHUNK_BYTES: usize = 4 * 1024 * 1024; // 4MB
+
+/// Export every row of `df` (patches applied, via `DataFrame::get_row`) to `format` ("csv" or
+/// "ndjson"), calling `chunk` with UTF-8 byte chunks of roughly `chunk_bytes` as they fill up.
+/// Returns the number of rows written.
+pub fn export_rows<C: FnMut(&[u8])>(
+    df: &DataFrame,
+    format: &str,
+    delimiter: u8,
+    include_header: bool,
+    chunk_bytes: usize,
+    chunk: C,
+) -> Result<usize, String> {
+    match format {
+        "csv" => export_csv(df, delimiter, include_header, chunk_bytes, chunk),
+        "ndjson" => export_ndjson(df, chunk_bytes, chunk),
+        other => Err(format!("Unknown export format: {} (expected \"csv\" or \"ndjson\")", other)),
+    }
+}
+
+/// A `Write` sink that buffers bytes and hands them to `chunk` once the buffer reaches
+/// `chunk_bytes`, then clears it. `finish` flushes whatever's left.
+struct ChunkSink<C: FnMut(&[u8])> {
+    buf: Vec<u8>,
+    chunk_bytes: usize,
+    chunk: C,
+}
+
+impl<C: FnMut(&[u8])> ChunkSink<C> {
+    fn new(chunk_bytes: usize, chunk: C) -> Self {
+        ChunkSink { buf: Vec::with_capacity(chunk_bytes + 4096), chunk_bytes, chunk }
+    }
+
+    fn finish(mut self) {
+        if !self.buf.is_empty() {
+            (self.chunk)(&self.buf);
+        }
+    }
+}
+
+impl<C: FnMut(&[u8])> Write for ChunkSink<C> {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        self.buf.extend_from_slice(data);
+        if self.buf.len() >= self.chunk_bytes {
+            (self.chunk)(&self.buf);
+            self.buf.clear();
+        }
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn export_csv<C: FnMut(&[u8])>(df: &DataFrame, delimiter: u8, include_header: bool, chunk_bytes: usize, chunk: C) -> Result<usize, String> {
+    let sink = ChunkSink::new(chunk_bytes, chunk);
+    let mut writer = csv::WriterBuilder::new().delimiter(delimiter).from_writer(sink);
+
+    if include_header {
+        let headers: Vec<&str> = df.columns.iter().map(|c| c.name.as_str()).collect();
+        writer.write_record(&headers).map_err(|e| e.to_string())?;
+    }
+    for row_idx in 0..df.rows {
+        let row = df.get_row(row_idx).unwrap_or_default();
+        writer.write_record(&row).map_err(|e| e.to_string())?;
+    }
+    writer.flush().map_err(|e| e.to_string())?;
+    writer.into_inner().map_err(|e| e.to_string())?.finish();
+    Ok(df.rows)
+}
+
+fn export_ndjson<C: FnMut(&[u8])>(df: &DataFrame, chunk_bytes: usize, chunk: C) -> Result<usize, String> {
+    let mut sink = ChunkSink::new(chunk_bytes, chunk);
+
+    for row_idx in 0..df.rows {
+        let row = df.get_row(row_idx).unwrap_or_default();
+        let mut obj = serde_json::Map::new();
+        for (col_idx, val) in row.into_iter().enumerate() {
+            if let Some(col) = df.columns.get(col_idx) {
+                obj.insert(col.name.clone(), serde_json::Value::String(val));
+            }
+        }
+        let line = serde_json::to_string(&serde_json::Value::Object(obj)).map_err(|e| e.to_string())?;
+        sink.write_all(line.as_bytes()).map_err(|e| e.to_string())?;
+        sink.write_all(b"\n").map_err(|e| e.to_string())?;
+    }
+    sink.finish();
+    Ok(df.rows)
+}
@@ -0,0 +1,74 @@
+//! Minimal expression language for computed/derived columns (see
+//! `DataFrame::add_computed_column`). An `Expr` is stored on the derived column's `ColumnSchema`
+//! and evaluated lazily per row inside `get_cell`/`get_row`/`validate_column`, never materialized,
+//! so it always reflects the latest patches on the columns it references.
+
+use serde::{Deserialize, Serialize};
+use super::dataframe::DataFrame;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op")]
+pub enum Expr {
+    /// Another column's value for the current row, resolved through `DataFrame::get_cell` so
+    /// patches on the referenced column are picked up automatically.
+    Column { index: usize },
+    /// A fixed string, the same for every row.
+    Literal { value: String },
+    /// String-concatenate the evaluated value of every operand.
+    Concat { args: Vec<Expr> },
+    /// A UTF-8-aware (char, not byte) substring: `start` chars in, up to `len` chars.
+    Substring { value: Box<Expr>, start: usize, len: usize },
+    Add { left: Box<Expr>, right: Box<Expr> },
+    Sub { left: Box<Expr>, right: Box<Expr> },
+    Mul { left: Box<Expr>, right: Box<Expr> },
+    Div { left: Box<Expr>, right: Box<Expr> },
+    /// Numeric comparison; formats as `"true"`/`"false"`, matching `ColumnType::Boolean`.
+    Gt { left: Box<Expr>, right: Box<Expr> },
+    Lt { left: Box<Expr>, right: Box<Expr> },
+    /// String equality (unlike `Gt`/`Lt`, not numeric-only); formats as `"true"`/`"false"`.
+    Eq { left: Box<Expr>, right: Box<Expr> },
+}
+
+impl Expr {
+    /// Evaluate this expression for `row_idx` against `df`.
+    pub fn eval(&self, df: &DataFrame, row_idx: usize) -> String {
+        match self {
+            Expr::Column { index } => df.get_cell(row_idx, *index).unwrap_or_default(),
+            Expr::Literal { value } => value.clone(),
+            Expr::Concat { args } => args.iter().map(|a| a.eval(df, row_idx)).collect(),
+            Expr::Substring { value, start, len } => {
+                value.eval(df, row_idx).chars().skip(*start).take(*len).collect()
+            }
+            Expr::Add { left, right } => format_number(num(left, df, row_idx) + num(right, df, row_idx)),
+            Expr::Sub { left, right } => format_number(num(left, df, row_idx) - num(right, df, row_idx)),
+            Expr::Mul { left, right } => format_number(num(left, df, row_idx) * num(right, df, row_idx)),
+            Expr::Div { left, right } => {
+                let divisor = num(right, df, row_idx);
+                if divisor == 0.0 {
+                    String::new()
+                } else {
+                    format_number(num(left, df, row_idx) / divisor)
+                }
+            }
+            Expr::Gt { left, right } => (num(left, df, row_idx) > num(right, df, row_idx)).to_string(),
+            Expr::Lt { left, right } => (num(left, df, row_idx) < num(right, df, row_idx)).to_string(),
+            Expr::Eq { left, right } => (left.eval(df, row_idx) == right.eval(df, row_idx)).to_string(),
+        }
+    }
+}
+
+/// Evaluate `expr` and parse it as a number; non-numeric or empty cells read as `0.0`, so a
+/// ragged/invalid source column degrades a computed arithmetic column instead of poisoning it.
+fn num(expr: &Expr, df: &DataFrame, row_idx: usize) -> f64 {
+    expr.eval(df, row_idx).trim().parse().unwrap_or(0.0)
+}
+
+/// Render a computed numeric result without a trailing `.0` on whole numbers, matching how
+/// Integer-typed cells look elsewhere in the app.
+fn format_number(n: f64) -> String {
+    if n.fract() == 0.0 && n.abs() < 1e15 {
+        (n as i64).to_string()
+    } else {
+        n.to_string()
+    }
+}
@@ -1,8 +1,9 @@
 use serde::{Serialize, Deserialize};
 use std::collections::{HashMap, HashSet};
 use super::dataframe::DataFrame;
-use super::schema::ColumnType;
+use super::schema::{self, ColumnType};
 use super::pii;
+use super::numfmt;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum Suggestion {
@@ -17,9 +18,10 @@ pub enum Suggestion {
     RedactSSN,
     RedactCreditCard,
     ZeroIPv4,
+    ZeroIPv6,
     // Boolean: yes/no/1/0/on/off -> true/false
     NormalizeBooleanExtended,
-    // Date: try multiple formats -> ISO; fallback 1970-01-01
+    // Date: try multiple calendar formats -> ISO; unparseable cells stay flagged (no 1970 fallback)
     NormalizeDateCascade,
     // Fuzzy: replace with closest master list value if distance <= max_distance
     FuzzyMatchCategorical { master_list: Vec<String>, max_distance: u32 },
@@ -27,6 +29,10 @@ pub enum Suggestion {
     NormalizeEmail,       // Remove duplicate @, trim, RFC-style
     NormalizePhoneE164,  // E.164: +1XXXXXXXXXX, strip extensions
     FormatPhoneUS,       // Format to US format: (XXX) XXX-XXXX or XXX-XXX-XXXX
+    // International mobile numbers -> E.164, per the column's dominant region (see schema::PHONE_INTL_REGIONS)
+    NormalizePhoneIntl { region: String },
+    // URL entity extraction + scheme/host lowercasing (see extract_url_entity)
+    NormalizeUrl,
     PadZipLeadingZeros,  // US ZIP: pad to 5 digits
     NormalizeStateAbbrev, // US state abbreviation -> full name
     // New standard types (Excel/Sheets-style)
@@ -34,6 +40,65 @@ pub enum Suggestion {
     NormalizeTimeToIso,  // HH:MM or 12h -> HH:MM:SS 24h
     NormalizeCurrency,   // Strip $€£,, format to 2 decimals
     NormalizePercentage, // Parse and format as "50" or "50%"
+    // Tier 3: locale/Excel-format-code-aware numeric normalization (see engine::numfmt)
+    NormalizeNumberFormat { format_code: String },
+    // Spelled-out English numbers -> digits, e.g. "twenty-five" -> "25"
+    NormalizeSpelledNumber,
+    // Spreadsheet numeric serial date (e.g. 44197) -> ISO, per the chosen date system
+    ExcelSerialToIso { system: ExcelDateSystem },
+}
+
+/// Which spreadsheet epoch a numeric serial date is counted from. See `excel_serial_to_iso`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum ExcelDateSystem {
+    /// Epoch 1899-12-30 (serial 1 = 1900-01-01), the default in Excel/Sheets on Windows.
+    /// Reproduces the historical Lotus 1-2-3 leap-day bug: serial 60 is the nonexistent
+    /// 1900-02-29 (rejected); the epoch absorbs that phantom day for serials >= 61, so serials
+    /// below 60 land one day later than a plain count from the epoch.
+    Excel1900,
+    /// Epoch 1904-01-01, used by legacy Mac Excel and some exports. No leap-day bug.
+    Excel1904,
+}
+
+/// Plausible range for a spreadsheet serial date (roughly 1950-01-01..2100-01-01 under the
+/// 1900 system). Narrows false positives from ordinary small integers in a Date column.
+fn is_plausible_excel_serial(serial: f64) -> bool {
+    (18_000.0..=73_050.0).contains(&serial)
+}
+
+/// Convert a spreadsheet numeric serial date to ISO 8601, per the 1900 or 1904 date system.
+/// 1900-system: serial N >= 61 = N days after epoch 1899-12-30, serial 60 (the nonexistent
+/// 1900-02-29 that Excel/Lotus count) is rejected, and serials below 60, which precede that
+/// phantom leap day, are N + 1 days after the epoch (serial 1 = 1900-01-01). 1904-system: serial N = N days after 1904-01-01, no
+/// leap-day correction needed. The fractional part of the serial, if any, is read as a
+/// time-of-day (`frac * 86400` seconds) and included in the output.
+pub fn excel_serial_to_iso(raw: &str, system: ExcelDateSystem) -> Option<String> {
+    let serial: f64 = raw.trim().parse().ok()?;
+    if serial < 0.0 {
+        return None;
+    }
+    let serial_floor = serial.floor();
+
+    let (epoch, days) = match system {
+        ExcelDateSystem::Excel1900 => {
+            if serial_floor == 60.0 {
+                return None;
+            }
+            let days = if serial_floor < 60.0 { serial_floor as i64 + 1 } else { serial_floor as i64 };
+            (chrono::NaiveDate::from_ymd_opt(1899, 12, 30)?, days)
+        }
+        ExcelDateSystem::Excel1904 => (chrono::NaiveDate::from_ymd_opt(1904, 1, 1)?, serial_floor as i64),
+    };
+    let date = epoch.checked_add_signed(chrono::Duration::days(days))?;
+
+    let frac = serial - serial_floor;
+    if frac > 0.0 {
+        let seconds = ((frac * 86_400.0).round() as i64).clamp(0, 86_399) as u32;
+        let time = chrono::NaiveTime::from_num_seconds_from_midnight_opt(seconds, 0)?;
+        Some(chrono::NaiveDateTime::new(date, time).format("%Y-%m-%d %H:%M:%S").to_string())
+    } else {
+        Some(date.format("%Y-%m-%d").to_string())
+    }
 }
 
 /// Boolean extended: true,t,yes,y,1,on,enabled -> true; false,f,no,n,0,off,disabled -> false (case insensitive).
@@ -49,19 +114,84 @@ pub fn normalize_boolean_extended(s: &str) -> Option<&'static str> {
     }
 }
 
-/// Date cascade: try YYYY-MM-DD, MM/DD/YYYY, DD-MM-YYYY, YYYY/MM/DD; fallback 1970-01-01.
-pub fn parse_date_cascade(s: &str) -> String {
+/// Extended ISO 8601 + common calendar-aware date cascade. Tries, in order: two-digit-year
+/// numeric formats (MM/DD/YY, DD-MM-YY, YY-MM-DD; pivoted 00-69 -> 20xx, 70-99 -> 19xx,
+/// chrono's own `%y` convention), plain numeric formats (YYYY-MM-DD, MM/DD/YYYY, DD-MM-YYYY,
+/// YYYY/MM/DD), ISO week dates (`2024-W05-3`, ISO weekday 1=Monday, using chrono's standard ISO
+/// week rule where week 1 contains the year's first Thursday), ordinal/day-of-year dates
+/// (`2024-035`), and month-name formats ("Jan 5, 2024", "5 January 2024"). Returns the ISO date plus a label naming which format matched (so
+/// `analyze_column` can surface *how* a value was interpreted), or `None` if nothing matched —
+/// unparseable cells are left for the caller to flag rather than coerced to a fake 1970 date.
+pub fn parse_date_cascade(s: &str) -> Option<(String, &'static str)> {
     let t = s.trim();
     if t.is_empty() {
-        return String::new();
+        return None;
     }
-    const FORMATS: &[&str] = &["%Y-%m-%d", "%m/%d/%Y", "%d-%m-%Y", "%Y/%m/%d"];
-    for fmt in FORMATS {
+
+    // Two-digit years first: chrono's `%Y` also accepts a two-digit year, so "01/05/24" would
+    // otherwise match `%m/%d/%Y` as year 0024. A four-digit year never matches these, since
+    // `%y` reads at most two digits and the rest is left over.
+    const TWO_DIGIT_YEAR_FORMATS: &[&str] = &["%m/%d/%y", "%d-%m-%y", "%y-%m-%d"];
+    for fmt in TWO_DIGIT_YEAR_FORMATS {
+        if let Ok(d) = chrono::NaiveDate::parse_from_str(t, fmt) {
+            return Some((d.format("%Y-%m-%d").to_string(), "two-digit year (pivoted)"));
+        }
+    }
+
+    const NUMERIC_FORMATS: &[(&str, &str)] = &[
+        ("%Y-%m-%d", "ISO 8601 (YYYY-MM-DD)"),
+        ("%m/%d/%Y", "US (MM/DD/YYYY)"),
+        ("%d-%m-%Y", "day-first (DD-MM-YYYY)"),
+        ("%Y/%m/%d", "ISO-slash (YYYY/MM/DD)"),
+    ];
+    for (fmt, label) in NUMERIC_FORMATS {
         if let Ok(d) = chrono::NaiveDate::parse_from_str(t, fmt) {
-            return d.format("%Y-%m-%d").to_string();
+            return Some((d.format("%Y-%m-%d").to_string(), label));
         }
     }
-    "1970-01-01".to_string()
+
+    if let Some(d) = parse_iso_week_date(t) {
+        return Some((d.format("%Y-%m-%d").to_string(), "ISO week date (YYYY-Www-D)"));
+    }
+
+    if let Ok(d) = chrono::NaiveDate::parse_from_str(t, "%Y-%j") {
+        return Some((d.format("%Y-%m-%d").to_string(), "ordinal date (YYYY-DDD)"));
+    }
+
+    const MONTH_NAME_FORMATS: &[(&str, &str)] = &[
+        ("%b %d, %Y", "month-name (Mon D, YYYY)"),
+        ("%B %d, %Y", "month-name (Month D, YYYY)"),
+        ("%d %B %Y", "month-name (D Month YYYY)"),
+        ("%d %b %Y", "month-name (D Mon YYYY)"),
+    ];
+    for (fmt, label) in MONTH_NAME_FORMATS {
+        if let Ok(d) = chrono::NaiveDate::parse_from_str(t, fmt) {
+            return Some((d.format("%Y-%m-%d").to_string(), label));
+        }
+    }
+
+    None
+}
+
+/// Parse `YYYY-Www-D` (e.g. "2024-W05-3", D = ISO weekday, 1=Monday..7=Sunday).
+fn parse_iso_week_date(t: &str) -> Option<chrono::NaiveDate> {
+    let b = t.as_bytes();
+    if t.len() != 10 || b[4] != b'-' || b[5] != b'W' || b[8] != b'-' {
+        return None;
+    }
+    let year: i32 = t[0..4].parse().ok()?;
+    let week: u32 = t[6..8].parse().ok()?;
+    let weekday = match t[9..10].parse::<u32>().ok()? {
+        1 => chrono::Weekday::Mon,
+        2 => chrono::Weekday::Tue,
+        3 => chrono::Weekday::Wed,
+        4 => chrono::Weekday::Thu,
+        5 => chrono::Weekday::Fri,
+        6 => chrono::Weekday::Sat,
+        7 => chrono::Weekday::Sun,
+        _ => return None,
+    };
+    chrono::NaiveDate::from_isoywd_opt(year, week, weekday)
 }
 
 /// UUID: 32 hex -> 8-4-4-4-12 lowercase; 36 with hyphens -> lowercase.
@@ -137,8 +267,91 @@ pub fn normalize_percentage(s: &str) -> String {
     }
 }
 
-/// Levenshtein distance between two strings.
-pub fn levenshtein(a: &str, b: &str) -> u32 {
+/// Convert a spelled-out English number ("one hundred twenty-three", "twenty-five", "two
+/// thousand fifty") to its digit string. Word-token parser: lowercase and split on
+/// spaces/hyphens (dropping "and"), map unit words (zero-nineteen) and tens words
+/// (twenty-ninety) into a running `current` sum, fold `current` on "hundred" (`current * 100`,
+/// or a bare 100 if nothing preceded it), and on each larger scale word ("thousand", "million",
+/// "billion") flush `current * scale` (or a bare `scale` if nothing preceded it) into `total`
+/// and reset `current`; the final `current` is added once all tokens are consumed. A leading
+/// "minus"/"negative" negates the result. Returns `None` if any token isn't a number word.
+pub fn normalize_spelled_number(s: &str) -> Option<String> {
+    let lower = s.trim().to_lowercase();
+    if lower.is_empty() {
+        return None;
+    }
+
+    let (negative, rest) = if let Some(r) = lower.strip_prefix("minus ") {
+        (true, r)
+    } else if let Some(r) = lower.strip_prefix("negative ") {
+        (true, r)
+    } else {
+        (false, lower.as_str())
+    };
+
+    let tokens: Vec<&str> = rest
+        .split(|c: char| c == ' ' || c == '-')
+        .filter(|w| !w.is_empty() && *w != "and")
+        .collect();
+    if tokens.is_empty() {
+        return None;
+    }
+
+    let mut total: i64 = 0;
+    let mut current: i64 = 0;
+    for tok in tokens {
+        if let Some(v) = spelled_unit_word(tok) {
+            current += v;
+        } else if let Some(v) = spelled_tens_word(tok) {
+            current += v;
+        } else if tok == "hundred" {
+            current = if current == 0 { 100 } else { current * 100 };
+        } else if let Some(scale) = spelled_scale_word(tok) {
+            let chunk = if current == 0 { 1 } else { current };
+            total += chunk * scale;
+            current = 0;
+        } else {
+            return None;
+        }
+    }
+
+    total += current;
+    if negative {
+        total = -total;
+    }
+    Some(total.to_string())
+}
+
+fn spelled_unit_word(w: &str) -> Option<i64> {
+    const UNITS: &[&str] = &[
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+        "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen",
+        "eighteen", "nineteen",
+    ];
+    UNITS.iter().position(|&u| u == w).map(|i| i as i64)
+}
+
+fn spelled_tens_word(w: &str) -> Option<i64> {
+    const TENS: &[(&str, i64)] = &[
+        ("twenty", 20), ("thirty", 30), ("forty", 40), ("fifty", 50),
+        ("sixty", 60), ("seventy", 70), ("eighty", 80), ("ninety", 90),
+    ];
+    TENS.iter().find(|(t, _)| *t == w).map(|(_, v)| *v)
+}
+
+fn spelled_scale_word(w: &str) -> Option<i64> {
+    match w {
+        "thousand" => Some(1_000),
+        "million" => Some(1_000_000),
+        "billion" => Some(1_000_000_000),
+        _ => None,
+    }
+}
+
+/// Damerau-Levenshtein distance (optimal string alignment variant): like classic Levenshtein,
+/// but adds a transposition operation so adjacent-character swaps (e.g. "Flodira" -> "Florida")
+/// cost 1 edit instead of 2, matching how human typos actually happen.
+pub fn damerau_levenshtein(a: &str, b: &str) -> u32 {
     let a: Vec<char> = a.chars().collect();
     let b: Vec<char> = b.chars().collect();
     let n = a.len();
@@ -149,34 +362,203 @@ pub fn levenshtein(a: &str, b: &str) -> u32 {
     if m == 0 {
         return n as u32;
     }
-    let mut prev = (0..=m as u32).collect::<Vec<_>>();
-    for (i, &ca) in a.iter().enumerate() {
-        let mut curr = vec![i as u32 + 1];
-        for (j, &cb) in b.iter().enumerate() {
-            let cost = if ca == cb { 0 } else { 1 };
-            curr.push((prev[j].saturating_add(cost))
-                .min(curr[j].saturating_add(1))
-                .min(prev[j + 1].saturating_add(1)));
+
+    let mut d = vec![vec![0u32; m + 1]; n + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i as u32;
+    }
+    for j in 0..=m {
+        d[0][j] = j as u32;
+    }
+    for i in 1..=n {
+        for j in 1..=m {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[n][m]
+}
+
+// fzf-style scoring constants (see fzf_score below). Named after fzf's own algo.go.
+const FZF_SCORE_MATCH: i64 = 16;
+const FZF_BONUS_BOUNDARY: i64 = 8;
+const FZF_BONUS_CONSECUTIVE: i64 = 8;
+const FZF_BONUS_FIRST_CHAR_MULTIPLIER: i64 = 2;
+const FZF_PENALTY_GAP_START: i64 = 3;
+const FZF_PENALTY_GAP_EXTENSION: i64 = 1;
+/// Minimum normalized fzf score to accept a candidate as a confident match.
+const FZF_MIN_CONFIDENCE: f64 = 0.6;
+
+/// fzf-style bonus-weighted subsequence alignment score: `pattern` is aligned as a subsequence
+/// inside `candidate` (Smith-Waterman-style DP over `h[i][j]`, i = pattern index, j = candidate
+/// index). A matched char scores a base amount plus a boundary bonus (previous char is a
+/// separator, or this is a lowercase->uppercase camel transition), a consecutive-match bonus
+/// when the previous pattern char also matched the previous candidate char, and a multiplier
+/// when matching the candidate's first char. Candidate chars skipped between matches cost a
+/// gap-start penalty followed by smaller gap-extension penalties. Trailing unmatched candidate
+/// chars after the last match are free. Returns `None` if `pattern` isn't a subsequence.
+pub fn fzf_score(pattern: &str, candidate: &str) -> Option<i64> {
+    let pat: Vec<char> = pattern.chars().flat_map(char::to_lowercase).collect();
+    let cand: Vec<char> = candidate.chars().collect();
+    let cand_lower: Vec<char> = candidate.chars().flat_map(char::to_lowercase).collect();
+    let n = pat.len();
+    let m = cand_lower.len();
+    if n == 0 || m == 0 || n > m {
+        return None;
+    }
+
+    let mut bonus = vec![0i64; m];
+    for j in 0..m {
+        bonus[j] = match j.checked_sub(1).map(|p| cand[p]) {
+            None => FZF_BONUS_BOUNDARY,
+            Some(prev) if !prev.is_alphanumeric() => FZF_BONUS_BOUNDARY,
+            Some(prev) if prev.is_lowercase() && cand[j].is_uppercase() => FZF_BONUS_BOUNDARY,
+            _ => 0,
+        };
+    }
+
+    const NEG: i64 = i64::MIN / 4;
+    // h[i][j]: best score aligning pat[0..i] within cand[0..j].
+    // run[i][j]: consecutive-match run length ending at h[i][j] (0 if it ended via a gap).
+    // gap_run[i][j]: consecutive skipped-candidate-char run length ending at h[i][j].
+    let mut h = vec![vec![0i64; m + 1]; n + 1];
+    let mut run = vec![vec![0u32; m + 1]; n + 1];
+    let mut gap_run = vec![vec![0u32; m + 1]; n + 1];
+    for i in 1..=n {
+        h[i][0] = NEG;
+    }
+
+    for i in 1..=n {
+        for j in 1..=m {
+            let match_h = if pat[i - 1] == cand_lower[j - 1] {
+                let diag = h[i - 1][j - 1];
+                if diag > NEG {
+                    let consecutive = if run[i - 1][j - 1] > 0 { run[i - 1][j - 1] + 1 } else { 1 };
+                    let mut bonus_add = if consecutive > 1 { FZF_BONUS_CONSECUTIVE } else { bonus[j - 1] };
+                    if j == 1 {
+                        bonus_add *= FZF_BONUS_FIRST_CHAR_MULTIPLIER;
+                    }
+                    Some((diag + FZF_SCORE_MATCH + bonus_add, consecutive))
+                } else {
+                    None
+                }
+            } else {
+                None
+            };
+
+            let skip_h = if h[i][j - 1] > NEG {
+                let g = gap_run[i][j - 1] + 1;
+                let penalty = if g == 1 { FZF_PENALTY_GAP_START } else { FZF_PENALTY_GAP_EXTENSION };
+                Some(h[i][j - 1] - penalty)
+            } else {
+                None
+            };
+
+            match (match_h, skip_h) {
+                (Some((mv, consecutive)), Some(sv)) if mv >= sv => {
+                    h[i][j] = mv;
+                    run[i][j] = consecutive;
+                    gap_run[i][j] = 0;
+                }
+                (Some((mv, consecutive)), None) => {
+                    h[i][j] = mv;
+                    run[i][j] = consecutive;
+                    gap_run[i][j] = 0;
+                }
+                (_, Some(sv)) => {
+                    h[i][j] = sv;
+                    run[i][j] = 0;
+                    gap_run[i][j] = gap_run[i][j - 1] + 1;
+                }
+                (None, None) => {
+                    h[i][j] = NEG;
+                }
+            }
         }
-        prev = curr;
     }
-    prev[m]
+
+    let best = (n..=m).map(|j| h[n][j]).max().unwrap_or(NEG);
+    if best > NEG {
+        Some(best)
+    } else {
+        None
+    }
 }
 
-/// Best match from master list (min Levenshtein); None if min distance > max_distance.
+/// `fzf_score` normalized to roughly 0.0–1.0 by the score an all-consecutive, all-boundary
+/// match of this length would earn. A heuristic, not an exact bound.
+fn fzf_confidence(pattern: &str, candidate: &str) -> Option<f64> {
+    let n = pattern.chars().count() as i64;
+    if n == 0 {
+        return None;
+    }
+    let score = fzf_score(pattern, candidate)?;
+    let max_possible = n * (FZF_SCORE_MATCH + FZF_BONUS_BOUNDARY * FZF_BONUS_FIRST_CHAR_MULTIPLIER);
+    Some((score as f64 / max_possible as f64).clamp(0.0, 1.0))
+}
+
+/// Best match from master list, combining two scorers: fzf-style bonus-weighted subsequence
+/// alignment (handles abbreviations/partial input, e.g. "Calif" -> "California") and
+/// Damerau-Levenshtein edit distance (handles same-length typos and transpositions, e.g.
+/// "Californa" -> "California", "Flodira" -> "Florida"). Returns the candidate from whichever
+/// scorer reports higher confidence; `None` if neither clears its threshold (`max_distance`
+/// edits, or `FZF_MIN_CONFIDENCE`).
 pub fn fuzzy_match_best(s: &str, master_list: &[String], max_distance: u32) -> Option<String> {
-    let s_lower = s.trim().to_lowercase();
-    if s_lower.is_empty() {
+    let trimmed = s.trim();
+    if trimmed.is_empty() {
         return None;
     }
-    let mut best: Option<(String, u32)> = None;
+    let s_lower = trimmed.to_lowercase();
+
+    // (candidate, distance, confidence) — ties broken toward the smallest distance, then the
+    // lexicographically smallest candidate, so a single deterministic replacement is suggested.
+    let mut best_lev: Option<(String, u32, f64)> = None;
+    let mut best_fzf: Option<(String, f64)> = None;
+
     for m in master_list {
-        let d = levenshtein(&s_lower, &m.to_lowercase());
-        if d <= max_distance && best.as_ref().map_or(true, |(_, bd)| d < *bd) {
-            best = Some((m.clone(), d));
+        let m_lower = m.to_lowercase();
+
+        let d = damerau_levenshtein(&s_lower, &m_lower);
+        if d <= max_distance {
+            let longest = s_lower.chars().count().max(m_lower.chars().count()).max(1) as f64;
+            let conf = 1.0 - (d as f64 / longest);
+            let better = match &best_lev {
+                None => true,
+                Some((cur_val, cur_d, cur_conf)) => {
+                    if conf != *cur_conf {
+                        conf > *cur_conf
+                    } else if d != *cur_d {
+                        d < *cur_d
+                    } else {
+                        m < cur_val
+                    }
+                }
+            };
+            if better {
+                best_lev = Some((m.clone(), d, conf));
+            }
+        }
+
+        if let Some(conf) = fzf_confidence(trimmed, m) {
+            if conf >= FZF_MIN_CONFIDENCE && best_fzf.as_ref().map_or(true, |(_, bc)| conf > *bc) {
+                best_fzf = Some((m.clone(), conf));
+            }
+        }
+    }
+
+    match (best_lev, best_fzf) {
+        (Some((lev_val, _, lev_conf)), Some((fzf_val, fzf_conf))) => {
+            Some(if fzf_conf > lev_conf { fzf_val } else { lev_val })
         }
+        (Some((v, _, _)), None) => Some(v),
+        (None, Some((v, _))) => Some(v),
+        (None, None) => None,
     }
-    best.map(|(s, _)| s)
 }
 
 /// US state names for fuzzy matching (master list).
@@ -279,6 +661,98 @@ pub fn format_phone_us(s: &str) -> Option<String> {
     Some(format!("({}) {}-{}", &ten_digits[..3], &ten_digits[3..6], &ten_digits[6..]))
 }
 
+/// Normalize a `region`'s mobile number to E.164: strip spaces/dashes/parentheses, drop a
+/// national trunk `0`, and prepend `+<calling code>`. Returns `None` if `region` isn't in
+/// `schema::PHONE_INTL_REGIONS` or the cleaned value doesn't match that region's pattern.
+pub fn normalize_phone_intl(s: &str, region: &str) -> Option<String> {
+    let entry = schema::PHONE_INTL_REGIONS.iter().find(|r| r.region == region)?;
+    let cleaned: String = s.chars().filter(|c| !matches!(c, ' ' | '-' | '(' | ')')).collect();
+    if !entry.regex.is_match(&cleaned) {
+        return None;
+    }
+    let digits: String = cleaned.chars().filter(|c| c.is_ascii_digit()).collect();
+    let national = digits
+        .strip_prefix(entry.calling_code)
+        .filter(|rest| entry.regex.is_match(&format!("+{}{}", entry.calling_code, rest)))
+        .or_else(|| digits.strip_prefix('0'))
+        .unwrap_or(&digits);
+    Some(format!("+{}{}", entry.calling_code, national))
+}
+
+/// Scan `s` left-to-right for a URL entity, the way a message-entity tokenizer finds links: a
+/// scheme (`http://`/`https://`) or a bare host with a known TLD (`schema::BARE_HOST_REGEX`),
+/// extended through path/query characters up to whitespace or an angle-bracket/quote, then
+/// trimmed of trailing sentence punctuation (`.`, `,`, `;`, `!`, `?`, `:`) and an unbalanced
+/// closing `)` with no matching `(` inside the URL. Returns the matched byte range within `s`.
+fn extract_url_entity(s: &str) -> Option<(usize, usize)> {
+    let lower = s.to_lowercase();
+    let start = lower
+        .find("https://")
+        .or_else(|| lower.find("http://"))
+        .or_else(|| schema::BARE_HOST_REGEX.find(s).map(|m| m.start()))?;
+
+    let bytes = s.as_bytes();
+    let mut end = start;
+    let mut paren_depth: i32 = 0;
+    while end < bytes.len() {
+        let c = bytes[end] as char;
+        if c.is_whitespace() || matches!(c, '<' | '>' | '"' | '\'') {
+            break;
+        }
+        if c == '(' {
+            paren_depth += 1;
+        } else if c == ')' {
+            if paren_depth == 0 {
+                break;
+            }
+            paren_depth -= 1;
+        }
+        end += 1;
+    }
+    while end > start {
+        let last = s[start..end].chars().last().unwrap();
+        if matches!(last, '.' | ',' | ';' | '!' | '?' | ':') {
+            end -= last.len_utf8();
+        } else {
+            break;
+        }
+    }
+
+    if end > start {
+        Some((start, end))
+    } else {
+        None
+    }
+}
+
+/// Normalize a cell's URL entity: strip wrapping `<>`/quotes, extract the URL entity
+/// (`extract_url_entity`), lowercase its scheme and host (but not path/query, which can be
+/// case-sensitive), and prepend `https://` when no scheme was present. Returns `None` if no URL
+/// entity is found, the result is unchanged, or it doesn't validate as `ColumnType::Url`.
+pub fn normalize_url(s: &str) -> Option<String> {
+    let stripped = s.trim().trim_matches(|c| matches!(c, '<' | '>' | '"' | '\''));
+    let (start, end) = extract_url_entity(stripped)?;
+    let entity = &stripped[start..end];
+
+    let has_https = entity.len() >= 8 && entity[..8].eq_ignore_ascii_case("https://");
+    let has_http = !has_https && entity.len() >= 7 && entity[..7].eq_ignore_ascii_case("http://");
+    let scheme_len = if has_https { 8 } else if has_http { 7 } else { 0 };
+    let scheme = if has_http { "http://" } else { "https://" };
+    let rest = &entity[scheme_len..];
+
+    let (host, path) = match rest.find(|c| matches!(c, '/' | '?' | '#')) {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, ""),
+    };
+    let normalized = format!("{}{}{}", scheme, host.to_lowercase(), path);
+
+    if normalized == s.trim() || !ColumnType::Url.is_valid(&normalized) {
+        None
+    } else {
+        Some(normalized)
+    }
+}
+
 /// US ZIP: pad with leading zeros to 5 digits (semantic: US ZIP must be 5 digits).
 pub fn pad_zip_leading_zeros(s: &str) -> Option<String> {
     let t = s.trim();
@@ -335,6 +809,129 @@ pub struct SuggestionReport {
     pub example_after: String,
 }
 
+/// Whether `suggestion` normalizes cells regardless of current validity (fuzzy match, E.164,
+/// etc.) rather than only ever touching already-invalid ones. Shared by `apply_suggestion` (to
+/// pick its row scan) and `should_apply_suggestion` (to pick its accept rule) so the two stay in
+/// sync.
+pub fn is_always_apply_normalizer(suggestion: &Suggestion) -> bool {
+    matches!(
+        suggestion,
+        Suggestion::FuzzyMatchCategorical { .. }
+            | Suggestion::NormalizeEmail
+            | Suggestion::NormalizePhoneE164
+            | Suggestion::NormalizePhoneIntl { .. }
+            | Suggestion::NormalizeUrl
+            | Suggestion::PadZipLeadingZeros
+            | Suggestion::NormalizeStateAbbrev
+            | Suggestion::NormalizeUuid
+            | Suggestion::NormalizeTimeToIso
+            | Suggestion::NormalizeCurrency
+            | Suggestion::NormalizePercentage
+            | Suggestion::NormalizeNumberFormat { .. }
+    )
+}
+
+/// Whether `suggestion` is a PII redaction (mask/zero/redact). These apply whenever the input
+/// matches the PII-ish pattern, not based on column-type validity.
+pub fn is_redaction_suggestion(suggestion: &Suggestion) -> bool {
+    matches!(
+        suggestion,
+        Suggestion::MaskEmail
+            | Suggestion::RedactSSN
+            | Suggestion::RedactCreditCard
+            | Suggestion::ZeroIPv4
+            | Suggestion::ZeroIPv6
+    )
+}
+
+/// Compute the candidate replacement for one cell under `suggestion`, and whether this is a
+/// redaction (see `is_redaction_suggestion`) so the caller can pick the right accept rule via
+/// `should_apply_suggestion`.
+pub fn compute_suggestion_value(suggestion: &Suggestion, old_val: &str) -> (String, bool) {
+    match suggestion {
+        Suggestion::TrimWhitespace => (old_val.trim().to_string(), false),
+        Suggestion::RemoveChars { chars } => (old_val.replace(chars, ""), false),
+        Suggestion::DigitsOnly => (old_val.chars().filter(|c| c.is_ascii_digit()).collect(), false),
+        Suggestion::PhoneStripToTenDigits => (normalize_phone_to_ten_digits(old_val), false),
+        Suggestion::NormalizeDateToIso => {
+            let trimmed = old_val.trim();
+            let v = if let Ok(d) = chrono::NaiveDate::parse_from_str(trimmed, "%m/%d/%Y") {
+                d.format("%Y-%m-%d").to_string()
+            } else {
+                old_val.to_string()
+            };
+            (v, false)
+        }
+        Suggestion::NormalizeBooleanCase => (old_val.trim().to_lowercase(), false),
+        Suggestion::MaskEmail => (pii::mask_email(old_val), true),
+        Suggestion::RedactSSN => (pii::redact_ssn(old_val), true),
+        Suggestion::RedactCreditCard => (pii::redact_credit_card(old_val), true),
+        Suggestion::ZeroIPv4 => (pii::zero_ipv4(old_val), true),
+        Suggestion::ZeroIPv6 => (pii::zero_ipv6(old_val), true),
+        Suggestion::NormalizeBooleanExtended => {
+            let v = normalize_boolean_extended(old_val).map(|s| s.to_string()).unwrap_or_else(|| old_val.to_string());
+            (v, false)
+        }
+        Suggestion::NormalizeDateCascade => (
+            parse_date_cascade(old_val).map(|(v, _)| v).unwrap_or_else(|| old_val.to_string()),
+            false,
+        ),
+        Suggestion::FuzzyMatchCategorical { master_list, max_distance } => {
+            let v = fuzzy_match_best(old_val, master_list, *max_distance).unwrap_or_else(|| old_val.to_string());
+            (v, false)
+        }
+        Suggestion::NormalizeEmail => {
+            let v = pii::normalize_email(old_val).unwrap_or_else(|| pii::email_remove_duplicate_at(old_val));
+            (v, false)
+        }
+        Suggestion::NormalizePhoneE164 => (normalize_phone_e164(old_val).unwrap_or_else(|| old_val.to_string()), false),
+        Suggestion::FormatPhoneUS => (format_phone_us(old_val).unwrap_or_else(|| old_val.to_string()), false),
+        Suggestion::NormalizePhoneIntl { region } => (
+            normalize_phone_intl(old_val, region).unwrap_or_else(|| old_val.to_string()),
+            false,
+        ),
+        Suggestion::NormalizeUrl => (normalize_url(old_val).unwrap_or_else(|| old_val.to_string()), false),
+        Suggestion::PadZipLeadingZeros => (pad_zip_leading_zeros(old_val).unwrap_or_else(|| old_val.to_string()), false),
+        Suggestion::NormalizeStateAbbrev => (normalize_state_abbrev(old_val).unwrap_or_else(|| old_val.to_string()), false),
+        Suggestion::NormalizeUuid => (normalize_uuid(old_val), false),
+        Suggestion::NormalizeTimeToIso => (normalize_time_to_iso(old_val).unwrap_or_else(|| old_val.to_string()), false),
+        Suggestion::NormalizeCurrency => (normalize_currency(old_val), false),
+        Suggestion::NormalizePercentage => (normalize_percentage(old_val), false),
+        Suggestion::NormalizeNumberFormat { format_code } => (
+            numfmt::normalize_with_format(old_val, format_code).unwrap_or_else(|| old_val.to_string()),
+            false,
+        ),
+        Suggestion::NormalizeSpelledNumber => (
+            normalize_spelled_number(old_val).unwrap_or_else(|| old_val.to_string()),
+            false,
+        ),
+        Suggestion::ExcelSerialToIso { system } => (
+            excel_serial_to_iso(old_val, *system).unwrap_or_else(|| old_val.to_string()),
+            false,
+        ),
+    }
+}
+
+/// Whether a just-computed `(old_val, new_val)` pair from `compute_suggestion_value` should
+/// actually be written back, given `col_type` and whether `suggestion` is a redaction.
+pub fn should_apply_suggestion(suggestion: &Suggestion, old_val: &str, new_val: &str, col_type: ColumnType) -> bool {
+    if is_redaction_suggestion(suggestion) {
+        let matches = match suggestion {
+            Suggestion::MaskEmail => pii::is_email_like(old_val),
+            Suggestion::RedactSSN => pii::is_ssn_like(old_val),
+            Suggestion::RedactCreditCard => pii::looks_like_credit_card(old_val),
+            Suggestion::ZeroIPv4 => pii::is_ipv4_like(old_val),
+            Suggestion::ZeroIPv6 => pii::is_ipv6_like(old_val),
+            _ => false,
+        };
+        matches && new_val != old_val
+    } else if is_always_apply_normalizer(suggestion) {
+        new_val != old_val
+    } else {
+        !col_type.is_valid(old_val) && new_val != old_val && col_type.is_valid(new_val)
+    }
+}
+
 pub fn analyze_column(df: &DataFrame, col_idx: usize) -> Vec<SuggestionReport> {
     let mut suggestions = Vec::new();
     let col_schema = &df.columns[col_idx];
@@ -357,17 +954,24 @@ pub fn analyze_column(df: &DataFrame, col_idx: usize) -> Vec<SuggestionReport> {
     let mut date_cascade_count: usize = 0;
     let mut ssn_count: usize = 0;
     let mut ipv4_count: usize = 0;
+    let mut ipv6_count: usize = 0;
     let mut email_mask_count: usize = 0;
     let mut cc_count: usize = 0;
     let mut fuzzy_states_count: usize = 0;
     let mut email_normalize_count: usize = 0;
     let mut phone_e164_count: usize = 0;
+    let mut phone_intl_match_counts: HashMap<&'static str, usize> = HashMap::new();
+    let mut phone_intl_changed_counts: HashMap<&'static str, usize> = HashMap::new();
+    let mut phone_intl_examples: HashMap<&'static str, (String, String)> = HashMap::new();
     let mut zip_pad_count: usize = 0;
     let mut state_abbrev_count: usize = 0;
     let mut uuid_normalize_count: usize = 0;
+    let mut url_normalize_count: usize = 0;
     let mut time_normalize_count: usize = 0;
     let mut currency_normalize_count: usize = 0;
     let mut percentage_normalize_count: usize = 0;
+    let mut spelled_number_count: usize = 0;
+    let mut excel_serial_candidate_count: usize = 0;
 
     let col_name_lower = col_schema.name.to_lowercase();
     let looks_like_zip = col_name_lower.contains("zip") || col_name_lower.contains("postal");
@@ -376,6 +980,7 @@ pub fn analyze_column(df: &DataFrame, col_idx: usize) -> Vec<SuggestionReport> {
     let mut trim_example_after = String::new();
     let mut ssn_example_before = String::new();
     let mut ipv4_example_before = String::new();
+    let mut ipv6_example_before = String::new();
     let mut email_mask_example_before = String::new();
     let mut cc_example_before = String::new();
     let mut fuzzy_example_before = String::new();
@@ -390,12 +995,18 @@ pub fn analyze_column(df: &DataFrame, col_idx: usize) -> Vec<SuggestionReport> {
     let mut state_abbrev_example_after = String::new();
     let mut uuid_example_before = String::new();
     let mut uuid_example_after = String::new();
+    let mut url_example_before = String::new();
+    let mut url_example_after = String::new();
     let mut time_example_before = String::new();
     let mut time_example_after = String::new();
     let mut currency_example_before = String::new();
     let mut currency_example_after = String::new();
     let mut percentage_example_before = String::new();
     let mut percentage_example_after = String::new();
+    let mut spelled_example_before = String::new();
+    let mut spelled_example_after = String::new();
+    let mut excel_serial_example_before = String::new();
+    let mut excel_serial_example_after = String::new();
 
     for row_idx in 0..rows_to_scan {
         if invalid_values.len() >= MAX_UNIQUE_INVALID_SAMPLE {
@@ -415,6 +1026,12 @@ pub fn analyze_column(df: &DataFrame, col_idx: usize) -> Vec<SuggestionReport> {
                         ipv4_example_before = val.clone();
                     }
                 }
+                if pii::is_ipv6_like(&val) {
+                    ipv6_count += 1;
+                    if ipv6_example_before.is_empty() {
+                        ipv6_example_before = val.clone();
+                    }
+                }
                 if pii::is_email_like(&val) {
                     email_mask_count += 1;
                     if email_mask_example_before.is_empty() {
@@ -454,6 +1071,22 @@ pub fn analyze_column(df: &DataFrame, col_idx: usize) -> Vec<SuggestionReport> {
                         }
                     }
                 }
+                if col_type == ColumnType::PhoneIntl {
+                    let cleaned: String = val.chars().filter(|c| !matches!(c, ' ' | '-' | '(' | ')')).collect();
+                    for phone_region in schema::PHONE_INTL_REGIONS.iter() {
+                        if phone_region.regex.is_match(&cleaned) {
+                            *phone_intl_match_counts.entry(phone_region.region).or_insert(0) += 1;
+                            if let Some(norm) = normalize_phone_intl(&val, phone_region.region) {
+                                if norm != val {
+                                    *phone_intl_changed_counts.entry(phone_region.region).or_insert(0) += 1;
+                                    phone_intl_examples
+                                        .entry(phone_region.region)
+                                        .or_insert_with(|| (val.clone(), norm));
+                                }
+                            }
+                        }
+                    }
+                }
                 if col_type == ColumnType::PhoneUS {
                     if let Some(e164) = normalize_phone_e164(&val) {
                         if e164 != val {
@@ -488,9 +1121,32 @@ pub fn analyze_column(df: &DataFrame, col_idx: usize) -> Vec<SuggestionReport> {
                 }
             }
             if col_type == ColumnType::Date {
-                let cascaded = parse_date_cascade(&val);
-                if !cascaded.is_empty() && cascaded != val.trim() {
-                    date_cascade_count += 1;
+                if let Some((cascaded, _)) = parse_date_cascade(&val) {
+                    if cascaded != val.trim() {
+                        date_cascade_count += 1;
+                    }
+                }
+                if let Ok(serial) = val.trim().parse::<f64>() {
+                    if is_plausible_excel_serial(serial) {
+                        excel_serial_candidate_count += 1;
+                        if excel_serial_example_before.is_empty() {
+                            if let Some(iso) = excel_serial_to_iso(&val, ExcelDateSystem::Excel1900) {
+                                excel_serial_example_before = val.clone();
+                                excel_serial_example_after = iso;
+                            }
+                        }
+                    }
+                }
+            }
+            if col_type == ColumnType::Integer || col_type == ColumnType::Float {
+                if let Some(spelled) = normalize_spelled_number(&val) {
+                    if col_type.is_valid(&spelled) {
+                        spelled_number_count += 1;
+                        if spelled_example_before.is_empty() {
+                            spelled_example_before = val.clone();
+                            spelled_example_after = spelled;
+                        }
+                    }
                 }
             }
 
@@ -548,6 +1204,17 @@ pub fn analyze_column(df: &DataFrame, col_idx: usize) -> Vec<SuggestionReport> {
                     }
                 }
             }
+            if col_type == ColumnType::Url {
+                if let Some(norm) = normalize_url(&val) {
+                    if col_type.is_valid(&norm) {
+                        url_normalize_count += 1;
+                        if url_example_before.is_empty() {
+                            url_example_before = val.clone();
+                            url_example_after = norm;
+                        }
+                    }
+                }
+            }
             if col_type == ColumnType::Time {
                 if let Some(norm) = normalize_time_to_iso(&val) {
                     if norm != val.trim() && col_type.is_valid(&norm) {
@@ -810,18 +1477,24 @@ pub fn analyze_column(df: &DataFrame, col_idx: usize) -> Vec<SuggestionReport> {
     if col_type == ColumnType::Date && date_cascade_count > 0 {
         let mut example_before = String::new();
         let mut example_after = String::new();
+        let mut matched_format = "";
         for val in &invalid_values {
-            let cascaded = parse_date_cascade(val);
-            if !cascaded.is_empty() && cascaded != val.trim() {
-                example_before = val.clone();
-                example_after = cascaded;
-                break;
+            if let Some((cascaded, format)) = parse_date_cascade(val) {
+                if cascaded != val.trim() {
+                    example_before = val.clone();
+                    example_after = cascaded;
+                    matched_format = format;
+                    break;
+                }
             }
         }
         if !example_before.is_empty() {
             suggestions.push(SuggestionReport {
                 suggestion: Suggestion::NormalizeDateCascade,
-                description: format!("Parse multiple date formats → ISO (fallback 1970-01-01) for {} cells", date_cascade_count),
+                description: format!(
+                    "Parse multiple date formats → ISO (matched {}) for {} cells",
+                    matched_format, date_cascade_count
+                ),
                 affected_rows_count: date_cascade_count,
                 example_before,
                 example_after,
@@ -847,6 +1520,15 @@ pub fn analyze_column(df: &DataFrame, col_idx: usize) -> Vec<SuggestionReport> {
             example_after: pii::zero_ipv4(&ipv4_example_before),
         });
     }
+    if ipv6_count > 0 && !ipv6_example_before.is_empty() {
+        suggestions.push(SuggestionReport {
+            suggestion: Suggestion::ZeroIPv6,
+            description: format!("Zero-out IPv6 (::) in {} cells", ipv6_count),
+            affected_rows_count: ipv6_count,
+            example_before: ipv6_example_before.clone(),
+            example_after: pii::zero_ipv6(&ipv6_example_before),
+        });
+    }
     if email_mask_count > 0 && !email_mask_example_before.is_empty() {
         suggestions.push(SuggestionReport {
             suggestion: Suggestion::MaskEmail,
@@ -897,6 +1579,24 @@ pub fn analyze_column(df: &DataFrame, col_idx: usize) -> Vec<SuggestionReport> {
             example_after: phone_e164_example_after.clone(),
         });
     }
+    if col_type == ColumnType::PhoneIntl && !phone_intl_match_counts.is_empty() {
+        let mut ranked: Vec<(&str, usize)> = phone_intl_match_counts.iter().map(|(&r, &n)| (r, n)).collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        if let Some(&(dominant, _)) = ranked.first() {
+            let changed = *phone_intl_changed_counts.get(dominant).unwrap_or(&0);
+            if let Some((example_before, example_after)) = phone_intl_examples.get(dominant) {
+                if changed > 0 {
+                    suggestions.push(SuggestionReport {
+                        suggestion: Suggestion::NormalizePhoneIntl { region: dominant.to_string() },
+                        description: format!("Normalize {} mobile numbers to E.164 in {} cells", dominant, changed),
+                        affected_rows_count: changed,
+                        example_before: example_before.clone(),
+                        example_after: example_after.clone(),
+                    });
+                }
+            }
+        }
+    }
     if looks_like_zip && zip_pad_count > 0 && !zip_pad_example_before.is_empty() {
         suggestions.push(SuggestionReport {
             suggestion: Suggestion::PadZipLeadingZeros,
@@ -916,6 +1616,15 @@ pub fn analyze_column(df: &DataFrame, col_idx: usize) -> Vec<SuggestionReport> {
         });
     }
 
+    if col_type == ColumnType::Url && url_normalize_count > 0 && !url_example_before.is_empty() {
+        suggestions.push(SuggestionReport {
+            suggestion: Suggestion::NormalizeUrl,
+            description: format!("Extract and normalize URL (scheme/host lowercased, https:// added if missing) in {} cells", url_normalize_count),
+            affected_rows_count: url_normalize_count,
+            example_before: url_example_before.clone(),
+            example_after: url_example_after.clone(),
+        });
+    }
     if col_type == ColumnType::Uuid && uuid_normalize_count > 0 && !uuid_example_before.is_empty() {
         suggestions.push(SuggestionReport {
             suggestion: Suggestion::NormalizeUuid,
@@ -952,6 +1661,129 @@ pub fn analyze_column(df: &DataFrame, col_idx: usize) -> Vec<SuggestionReport> {
             example_after: percentage_example_after.clone(),
         });
     }
+    if matches!(col_type, ColumnType::Currency | ColumnType::Percentage) {
+        if let Some(locale) = numfmt::detect_column_locale(invalid_values.iter().map(|s| s.as_str())) {
+            if locale != numfmt::Locale::EnUs {
+                let decimals = if col_type == ColumnType::Percentage { 0 } else { 2 };
+                let format_code = numfmt::format_code_for_locale(locale, decimals);
+                let mut locale_count = 0usize;
+                let mut locale_example_before = String::new();
+                let mut locale_example_after = String::new();
+                for val in &invalid_values {
+                    if let Some(localized) = numfmt::normalize_with_format(val, &format_code) {
+                        locale_count += 1;
+                        if locale_example_before.is_empty() {
+                            locale_example_before = val.clone();
+                            locale_example_after = localized;
+                        }
+                    }
+                }
+                if locale_count > 0 && !locale_example_before.is_empty() {
+                    suggestions.push(SuggestionReport {
+                        suggestion: Suggestion::NormalizeNumberFormat { format_code },
+                        description: format!(
+                            "Parse as {} instead of US format in {} cells",
+                            numfmt::locale_label(locale),
+                            locale_count
+                        ),
+                        affected_rows_count: locale_count,
+                        example_before: locale_example_before,
+                        example_after: locale_example_after,
+                    });
+                }
+            }
+        }
+    }
+    if col_type == ColumnType::Date
+        && excel_serial_candidate_count > 0
+        && !excel_serial_example_before.is_empty()
+        && (excel_serial_candidate_count as f64 / invalid_values.len() as f64) > 0.9
+    {
+        suggestions.push(SuggestionReport {
+            suggestion: Suggestion::ExcelSerialToIso { system: ExcelDateSystem::Excel1900 },
+            description: format!(
+                "Convert spreadsheet serial dates (1900 system) to ISO for {} cells",
+                excel_serial_candidate_count
+            ),
+            affected_rows_count: excel_serial_candidate_count,
+            example_before: excel_serial_example_before.clone(),
+            example_after: excel_serial_example_after.clone(),
+        });
+    }
+    if (col_type == ColumnType::Integer || col_type == ColumnType::Float)
+        && spelled_number_count > 0
+        && !spelled_example_before.is_empty()
+    {
+        suggestions.push(SuggestionReport {
+            suggestion: Suggestion::NormalizeSpelledNumber,
+            description: format!("Convert spelled-out numbers to digits in {} cells", spelled_number_count),
+            affected_rows_count: spelled_number_count,
+            example_before: spelled_example_before.clone(),
+            example_after: spelled_example_after.clone(),
+        });
+    }
 
     suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn damerau_levenshtein_same_length_typo() {
+        assert_eq!(damerau_levenshtein("Californa", "California"), 1);
+    }
+
+    #[test]
+    fn damerau_levenshtein_transposition_counts_as_one_edit() {
+        assert_eq!(damerau_levenshtein("Flodira", "Florida"), 1);
+    }
+
+    #[test]
+    fn damerau_levenshtein_identical_strings() {
+        assert_eq!(damerau_levenshtein("Texas", "Texas"), 0);
+    }
+
+    #[test]
+    fn damerau_levenshtein_empty_string_is_length_of_other() {
+        assert_eq!(damerau_levenshtein("", "Texas"), 5);
+        assert_eq!(damerau_levenshtein("Texas", ""), 5);
+    }
+
+    #[test]
+    fn fzf_score_requires_pattern_to_be_a_subsequence() {
+        assert!(fzf_score("xyz", "California").is_none());
+    }
+
+    #[test]
+    fn fzf_score_rewards_consecutive_and_boundary_matches() {
+        let prefix = fzf_score("cal", "California").unwrap();
+        let scattered = fzf_score("cin", "California").unwrap();
+        assert!(prefix > scattered);
+    }
+
+    #[test]
+    fn fuzzy_match_best_finds_transposition_via_edit_distance() {
+        let master = vec!["Florida".to_string(), "Georgia".to_string()];
+        assert_eq!(fuzzy_match_best("Flodira", &master, 2), Some("Florida".to_string()));
+    }
+
+    #[test]
+    fn fuzzy_match_best_finds_abbreviation_via_fzf_score() {
+        let master = vec!["California".to_string(), "Colorado".to_string()];
+        assert_eq!(fuzzy_match_best("Calif", &master, 1), Some("California".to_string()));
+    }
+
+    #[test]
+    fn fuzzy_match_best_returns_none_below_both_thresholds() {
+        let master = vec!["Florida".to_string(), "Georgia".to_string()];
+        assert_eq!(fuzzy_match_best("Alaska", &master, 1), None);
+    }
+
+    #[test]
+    fn fuzzy_match_best_empty_input_returns_none() {
+        let master = vec!["Florida".to_string()];
+        assert_eq!(fuzzy_match_best("   ", &master, 2), None);
+    }
 }
\ No newline at end of file
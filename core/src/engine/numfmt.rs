@@ -0,0 +1,251 @@
+//! Locale- and Excel-format-code-aware number/currency/percentage normalization.
+//!
+//! Spreadsheet number formats are split into `positive;negative;zero[;text]` sections so the
+//! same format code can render differently depending on the value's sign. We borrow that shape:
+//! a [`NumberFormat`] carries up to three [`NumberSection`]s (the `text` section is out of scope
+//! here — it only matters for non-numeric cells), and each section picks a [`Locale`]'s
+//! decimal/grouping separators plus an optional currency symbol and its placement.
+//!
+//! Compact format code grammar (one section): `<locale>:<currency-symbol|->:<prefix|suffix>:<decimals>`,
+//! sections joined by `;`. Example: `"de-DE:€:prefix:2"` renders `1234.5` as `€1.234,50`.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    /// 1,234.56 — comma grouping, dot decimal (en-US, en-GB).
+    EnUs,
+    /// 1.234,56 — dot grouping, comma decimal (de-DE, es-ES, it-IT).
+    DeDe,
+    /// 1 234,56 — space grouping, comma decimal (fr-FR).
+    FrFr,
+}
+
+impl Locale {
+    fn from_tag(tag: &str) -> Option<Locale> {
+        match tag {
+            "en-US" | "en-GB" => Some(Locale::EnUs),
+            "de-DE" | "es-ES" | "it-IT" => Some(Locale::DeDe),
+            "fr-FR" => Some(Locale::FrFr),
+            _ => None,
+        }
+    }
+
+    /// (decimal separator, grouping separator)
+    fn separators(self) -> (char, char) {
+        match self {
+            Locale::EnUs => ('.', ','),
+            Locale::DeDe => (',', '.'),
+            Locale::FrFr => (',', ' '),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurrencyPlacement {
+    Prefix,
+    Suffix,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberSection {
+    pub locale: Locale,
+    pub currency_symbol: Option<String>,
+    pub currency_placement: CurrencyPlacement,
+    pub decimals: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct NumberFormat {
+    pub positive: NumberSection,
+    pub negative: NumberSection,
+    pub zero: NumberSection,
+}
+
+fn parse_section(code: &str) -> Option<NumberSection> {
+    let parts: Vec<&str> = code.split(':').collect();
+    if parts.len() != 4 {
+        return None;
+    }
+    let locale = Locale::from_tag(parts[0])?;
+    let currency_symbol = if parts[1] == "-" { None } else { Some(parts[1].to_string()) };
+    let currency_placement = match parts[2] {
+        "prefix" => CurrencyPlacement::Prefix,
+        "suffix" => CurrencyPlacement::Suffix,
+        _ => return None,
+    };
+    let decimals = parts[3].parse::<usize>().ok()?;
+    Some(NumberSection { locale, currency_symbol, currency_placement, decimals })
+}
+
+/// Parse a `positive;negative;zero[;text]` format code. The `negative`/`zero` sections default
+/// to the `positive` section when omitted; the optional `text` section is accepted but neither
+/// parsed nor used.
+pub fn parse_format(format_code: &str) -> Option<NumberFormat> {
+    let sections: Vec<&str> = format_code.split(';').collect();
+    if sections.is_empty() || sections.len() > 4 {
+        return None;
+    }
+    let positive = parse_section(sections[0])?;
+    let negative = match sections.get(1) {
+        Some(s) => parse_section(s)?,
+        None => positive.clone(),
+    };
+    let zero = match sections.get(2) {
+        Some(s) => parse_section(s)?,
+        None => positive.clone(),
+    };
+    Some(NumberFormat { positive, negative, zero })
+}
+
+/// Parse `raw` using `locale`'s separators, stripping known currency symbols, `%`, and
+/// whitespace first so grouping chars are dropped and the correct decimal char is recognized.
+pub fn parse_with_locale(raw: &str, locale: Locale) -> Option<f64> {
+    let (decimal, grouping) = locale.separators();
+    let cleaned: String = raw
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, '$' | '€' | '£' | '¥' | '%') && !c.is_whitespace())
+        .collect();
+    if cleaned.is_empty() {
+        return None;
+    }
+    let mut normalized = String::with_capacity(cleaned.len());
+    for c in cleaned.chars() {
+        if c == grouping {
+            continue;
+        } else if c == decimal {
+            normalized.push('.');
+        } else {
+            normalized.push(c);
+        }
+    }
+    normalized.parse::<f64>().ok()
+}
+
+/// Re-emit `value` per the format's active section (negative for <0, zero for ==0.0, positive
+/// otherwise), at that section's decimal count, locale separators, and currency symbol/placement.
+pub fn format_with_sections(value: f64, fmt: &NumberFormat) -> String {
+    let section = if value < 0.0 {
+        &fmt.negative
+    } else if value == 0.0 {
+        &fmt.zero
+    } else {
+        &fmt.positive
+    };
+    format_section(value.abs(), section, value < 0.0)
+}
+
+fn format_section(abs_value: f64, section: &NumberSection, is_negative: bool) -> String {
+    let (decimal, grouping) = section.locale.separators();
+    let fixed = format!("{:.*}", section.decimals, abs_value);
+    let (int_part, frac_part) = match fixed.split_once('.') {
+        Some((i, f)) => (i, Some(f)),
+        None => (fixed.as_str(), None),
+    };
+
+    let digits: Vec<char> = int_part.chars().collect();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.iter().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(grouping);
+        }
+        grouped.push(*c);
+    }
+
+    let mut number = grouped;
+    if let Some(frac) = frac_part {
+        number.push(decimal);
+        number.push_str(frac);
+    }
+    if is_negative {
+        number = format!("-{}", number);
+    }
+
+    match &section.currency_symbol {
+        Some(sym) => match section.currency_placement {
+            CurrencyPlacement::Prefix => format!("{}{}", sym, number),
+            CurrencyPlacement::Suffix => format!("{}{}", number, sym),
+        },
+        None => number,
+    }
+}
+
+/// Parse `raw` under `format_code`'s (positive section's) locale and re-emit it per the
+/// matching section. `None` if the format code is malformed or `raw` doesn't parse under it.
+pub fn normalize_with_format(raw: &str, format_code: &str) -> Option<String> {
+    let fmt = parse_format(format_code)?;
+    let value = parse_with_locale(raw, fmt.positive.locale)?;
+    Some(format_with_sections(value, &fmt))
+}
+
+/// Format code for `locale` at `decimals` decimal places, no fixed currency symbol — the shape
+/// [`normalize_with_format`] needs to re-parse a value once its locale has been inferred.
+pub fn format_code_for_locale(locale: Locale, decimals: usize) -> String {
+    let tag = match locale {
+        Locale::EnUs => "en-US",
+        Locale::DeDe => "de-DE",
+        Locale::FrFr => "fr-FR",
+    };
+    format!("{}:-:prefix:{}", tag, decimals)
+}
+
+/// Human-readable label for `locale`'s separator convention, for use in suggestion descriptions.
+pub fn locale_label(locale: Locale) -> &'static str {
+    match locale {
+        Locale::EnUs => "US/UK (',' group, '.' decimal)",
+        Locale::DeDe => "German/Spanish/Italian ('.' group, ',' decimal)",
+        Locale::FrFr => "French (' ' group, ',' decimal)",
+    }
+}
+
+/// Infer which separator convention a single raw value uses, by position: if a `,` appears after
+/// the last `.`, the comma is the decimal separator (continental style) and vice versa. When only
+/// one kind of separator is present, a lone separator with exactly three trailing digits (e.g.
+/// `"1,234"`) is treated as a grouping separator rather than a decimal point (e.g. `"1,23"`). A
+/// space or NBSP anywhere is always the grouping separator (French style). Returns `None` when the
+/// value has no separator to judge from.
+///
+/// Also used by `schema::is_valid_currency` to reject non-US-shaped values (instead of silently
+/// misparsing them) so they land in `analyze_column`'s `invalid_values` and this same heuristic
+/// can surface a `NormalizeNumberFormat` suggestion for them.
+pub fn detect_value_locale(raw: &str) -> Option<Locale> {
+    if raw.chars().any(|c| c == ' ' || c == '\u{a0}') {
+        return Some(Locale::FrFr);
+    }
+
+    let last_dot = raw.rfind('.');
+    let last_comma = raw.rfind(',');
+    match (last_dot, last_comma) {
+        (Some(d), Some(c)) => Some(if c > d { Locale::DeDe } else { Locale::EnUs }),
+        (Some(d), None) => {
+            let trailing = raw[d + 1..].chars().take_while(|c| c.is_ascii_digit()).count();
+            Some(if trailing == 3 { Locale::DeDe } else { Locale::EnUs })
+        }
+        (None, Some(c)) => {
+            let trailing = raw[c + 1..].chars().take_while(|c| c.is_ascii_digit()).count();
+            Some(if trailing == 3 { Locale::EnUs } else { Locale::DeDe })
+        }
+        (None, None) => None,
+    }
+}
+
+/// Infer the dominant locale across a column's sampled values by majority vote of
+/// [`detect_value_locale`]. Returns `None` when no sample yields a confident vote (e.g. all
+/// values are plain integers with no separator).
+pub fn detect_column_locale<'a>(samples: impl Iterator<Item = &'a str>) -> Option<Locale> {
+    let mut en_us = 0usize;
+    let mut de_de = 0usize;
+    let mut fr_fr = 0usize;
+    for s in samples {
+        match detect_value_locale(s) {
+            Some(Locale::EnUs) => en_us += 1,
+            Some(Locale::DeDe) => de_de += 1,
+            Some(Locale::FrFr) => fr_fr += 1,
+            None => {}
+        }
+    }
+    [(en_us, Locale::EnUs), (de_de, Locale::DeDe), (fr_fr, Locale::FrFr)]
+        .into_iter()
+        .filter(|(n, _)| *n > 0)
+        .max_by_key(|(n, _)| *n)
+        .map(|(_, l)| l)
+}
@@ -1,6 +1,7 @@
 use crate::engine::dataframe::DataFrame;
 use crate::engine::schema::{ColumnType, ColumnSchema};
 use std::io::Cursor;
+use std::sync::Arc;
 use csv::ReaderBuilder;
 
 /// Progress report: call every PROGRESS_INTERVAL bytes during byte scan.
@@ -8,9 +9,56 @@ use csv::ReaderBuilder;
 /// 8MB keeps progress bar smooth (~40–75 updates for 300–600MB) while avoiding 3s+ slowdown.
 const PROGRESS_INTERVAL: usize = 8_388_608; // 8MB
 
+/// Default number of data rows materialized between [`parse_csv_batched`] batch callbacks.
+/// Small enough that the first callback (and thus type inference + the first rendered page)
+/// doesn't have to wait for a 600MB upload to finish indexing.
+pub const DEFAULT_BATCH_ROWS: usize = 50_000;
+
 /// Parse raw bytes into a DataFrame and infer types using a lazy scan approach.
 /// If `progress` is Some, it is called during the byte scan with (bytes_scanned, total_bytes).
+/// Rows whose field count doesn't match the header are quarantined into `DataFrame::bad_rows`
+/// rather than corrupting column alignment; no share of bad rows makes this fail (see
+/// [`parse_csv_with_threshold`] for the scrubcsv-style "fail if >10% bad" variant).
 pub fn parse_csv<F: FnMut(usize, usize)>(data: &[u8], mut progress: Option<F>) -> Result<DataFrame, Box<dyn std::error::Error>> {
+    parse_csv_batched(data, DEFAULT_BATCH_ROWS, progress.as_mut(), None::<fn(&DataFrame)>, None)
+}
+
+/// Same as [`parse_csv`] (including quarantining rows whose field count doesn't match the
+/// header into `bad_rows`), additionally failing if more than `bad_row_threshold`% of rows are
+/// bad.
+pub fn parse_csv_with_threshold<F: FnMut(usize, usize)>(data: &[u8], mut progress: Option<&mut F>, bad_row_threshold: Option<f64>) -> Result<DataFrame, Box<dyn std::error::Error>> {
+    parse_csv_batched(data, DEFAULT_BATCH_ROWS, progress.as_mut(), None::<fn(&DataFrame)>, bad_row_threshold)
+}
+
+/// Batched core that [`parse_csv`] and [`parse_csv_with_threshold`] are thin wrappers over.
+///
+/// The single-shot parser used to walk `data` twice: once to find quote-aware row boundaries,
+/// then a second full pass re-reading every record from scratch to validate its field count
+/// (on top of cloning the whole buffer into the returned `DataFrame`). That's a full duplicate
+/// scan and a large `row_indices` buffer materialized all at once before the caller can do
+/// anything, which doesn't scale to a 600MB upload.
+///
+/// This folds boundary detection and record materialization into one forward pass — a row's
+/// field count is validated the moment its closing newline is found, in lockstep with a single
+/// `csv::Reader` walking the same bytes — and processes it in `batch_rows`-row chunks. After
+/// every `batch_rows` data rows are indexed and validated, `on_batch` (if given) is called with
+/// a `DataFrame` snapshot of everything indexed so far (sharing the same underlying buffer via
+/// `Arc`, not a re-clone), so the caller can start type-inference sampling and render early pages
+/// before the whole file is resident. `progress` still fires on the byte scan, same as
+/// `parse_csv`.
+pub fn parse_csv_batched<F, B>(
+    data: &[u8],
+    batch_rows: usize,
+    mut progress: Option<F>,
+    mut on_batch: Option<B>,
+    bad_row_threshold: Option<f64>,
+) -> Result<DataFrame, Box<dyn std::error::Error>>
+where
+    F: FnMut(usize, usize),
+    B: FnMut(&DataFrame),
+{
+    let batch_rows = batch_rows.max(1);
+
     // 1. Extract Headers (Parse first line)
     let cursor = Cursor::new(data);
     let mut rdr = ReaderBuilder::new()
@@ -18,50 +66,99 @@ pub fn parse_csv<F: FnMut(usize, usize)>(data: &[u8], mut progress: Option<F>) -
         .from_reader(cursor);
 
     let headers: Vec<String> = rdr.headers()?.iter().map(|s| s.to_string()).collect();
-    
+
     // Initialize columns
     let columns: Vec<ColumnSchema> = headers.into_iter()
         .map(|name| ColumnSchema {
             name,
             detected_type: ColumnType::Text,
+            computed: None,
         })
         .collect();
+    let expected_cols = columns.len();
+
+    // Own the bytes once; batch snapshots below share this via `Arc::clone` instead of cloning
+    // the whole upload again per batch.
+    let raw_data = Arc::new(data.to_vec());
+
+    // 2+3. Quote-aware row-boundary scan, folded together with field-count validation. A naive
+    // byte-by-byte scan for `b'\n'` would split any record that has a newline embedded in a
+    // quoted field, so this tracks whether the cursor is inside a quoted field (toggled on `"`,
+    // with `""` treated as an escaped quote rather than a toggle) and only treats a newline as a
+    // row boundary outside quotes. `body_rdr` walks the same bytes record-by-record (skipping
+    // the header automatically) and is advanced exactly once per row boundary found below, so
+    // every row is validated the moment it's fully scanned rather than in a separate pass.
+    let mut body_rdr = ReaderBuilder::new().has_headers(true).from_reader(Cursor::new(data));
+    let mut record = csv::StringRecord::new();
+
+    let mut row_indices: Vec<usize> = Vec::new();
+    let mut good_row_indices: Vec<usize> = Vec::new();
+    let mut bad_rows: Vec<(usize, String)> = Vec::new();
+    let mut validated = 0usize; // number of row_indices entries validated (good + bad) so far
+    let mut rows_since_batch = 0usize;
 
-    // 2. Fast Scan for Row Indices
-    // We need to skip the header line.
-    // Let's find where the first record starts.
-    // The reader has read the header. The underlying reader position might be at the start of data?
-    // csv::Reader doesn't easily give byte offset of the data start.
-    // So we'll scan manually for the first newline.
-    
-    let mut row_indices = Vec::new();
     let mut current_pos = 0;
     let total = data.len();
+    let mut in_quotes = false;
+    // Whether the next byte begins a new field (absolute start of input, or right after an
+    // unquoted `,`/`\n`). A `"` only opens a quoted field when it lands here; a `"` anywhere
+    // else in an unquoted field (e.g. `12" pipe`) is just a literal character, matching the
+    // `csv` crate's own quoting rules (RFC 4180) instead of toggling quote state on every quote
+    // byte and misreading row boundaries.
+    let mut at_field_start = true;
+    let mut last_progress_at = 0usize;
+    let mut header_seen = false;
 
-    // Find end of header
-    while current_pos < data.len() {
-        if data[current_pos] == b'\n' {
-            current_pos += 1;
-            break;
-        }
-        current_pos += 1;
-    }
-    if let Some(ref mut p) = progress {
-        p(current_pos, total);
-    }
-    let mut last_progress_at = current_pos;
-    
-    // current_pos is now at the start of the first data row
-    if current_pos < data.len() {
-        row_indices.push(current_pos);
-    }
-    
-    // Scan the rest
     while current_pos < data.len() {
-        if data[current_pos] == b'\n' {
-            let next_start = current_pos + 1;
-            if next_start < data.len() {
-                row_indices.push(next_start);
+        match data[current_pos] {
+            b'"' => {
+                if in_quotes {
+                    if data.get(current_pos + 1) == Some(&b'"') {
+                        // `""` is an escaped literal quote inside a quoted field; skip the pair
+                        // without toggling quote state.
+                        current_pos += 1;
+                    } else {
+                        in_quotes = false;
+                    }
+                } else if at_field_start {
+                    in_quotes = true;
+                }
+                at_field_start = false;
+            }
+            b',' if !in_quotes => {
+                at_field_start = true;
+            }
+            b'\n' if !in_quotes => {
+                let next_start = current_pos + 1;
+                if !header_seen {
+                    header_seen = true;
+                    if let Some(ref mut p) = progress {
+                        p(next_start, total);
+                    }
+                    last_progress_at = next_start;
+                } else {
+                    // The row that started at the last recorded boundary just ended here, so
+                    // it's fully scanned now: validate it before moving on.
+                    let idx = row_indices.len() - 1;
+                    let start = row_indices[idx];
+                    validate_row(idx, start, current_pos, data, &mut body_rdr, &mut record, expected_cols, &mut good_row_indices, &mut bad_rows);
+                    validated += 1;
+                    rows_since_batch += 1;
+                    if rows_since_batch >= batch_rows {
+                        rows_since_batch = 0;
+                        if let Some(ref mut cb) = on_batch {
+                            let snapshot = DataFrame::from_shared(Arc::clone(&raw_data), good_row_indices.clone(), columns.clone(), bad_rows.clone());
+                            cb(&snapshot);
+                        }
+                    }
+                }
+                if next_start < data.len() {
+                    row_indices.push(next_start);
+                }
+                at_field_start = true;
+            }
+            _ => {
+                at_field_start = false;
             }
         }
         current_pos += 1;
@@ -76,15 +173,38 @@ pub fn parse_csv<F: FnMut(usize, usize)>(data: &[u8], mut progress: Option<F>) -
         p(total, total);
     }
 
-    // 3. Create DataFrame
-    // We clone the data here. The prompt says "Store raw_data: Vec<u8>". 
-    // data is &[u8]. So we must clone.
-    let mut df = DataFrame::new(data.to_vec(), row_indices, columns);
+    // When the file doesn't end in a newline, the very last row had no newline to trigger
+    // validation above, so validate it here. When it does, that newline already validated it
+    // (and, at EOF, pushed no phantom next row), so this is skipped.
+    if validated < row_indices.len() {
+        let idx = row_indices.len() - 1;
+        let start = row_indices[idx];
+        validate_row(idx, start, data.len(), data, &mut body_rdr, &mut record, expected_cols, &mut good_row_indices, &mut bad_rows);
+    }
+
+    if let Some(threshold) = bad_row_threshold {
+        let total_scanned = row_indices.len();
+        if total_scanned > 0 {
+            let bad_pct = bad_rows.len() as f64 / total_scanned as f64 * 100.0;
+            if bad_pct > threshold {
+                return Err(format!(
+                    "{:.1}% of rows ({}/{}) have a field count that doesn't match the header, exceeding the {:.1}% threshold",
+                    bad_pct, bad_rows.len(), total_scanned, threshold
+                ).into());
+            }
+        }
+    }
 
-    // 4. Infer Types (First 100 rows)
+    // 4. Create the final DataFrame, sharing the same buffer as any batch snapshots above.
+    let mut df = DataFrame::from_shared(raw_data, good_row_indices, columns, bad_rows);
+    if let Some(ref mut cb) = on_batch {
+        cb(&df);
+    }
+
+    // 5. Infer Types (First 100 rows)
     let col_count = df.columns.len();
     let rows_to_scan = std::cmp::min(df.rows, 100);
-    
+
     for i in 0..col_count {
         let mut sample_values = Vec::new();
         for r in 0..rows_to_scan {
@@ -92,7 +212,7 @@ pub fn parse_csv<F: FnMut(usize, usize)>(data: &[u8], mut progress: Option<F>) -
                 sample_values.push(val);
             }
         }
-        
+
         let inferred_type = infer_column_type(&sample_values);
         df.set_column_type(i, inferred_type);
     }
@@ -100,6 +220,122 @@ pub fn parse_csv<F: FnMut(usize, usize)>(data: &[u8], mut progress: Option<F>) -
     Ok(df)
 }
 
+/// Validate the row at `row_indices[idx]` (spanning `[start, end)` in `data`) by advancing
+/// `body_rdr` exactly one record: if its field count matches `expected_cols`, its start byte is
+/// kept in `good_row_indices`; otherwise its raw text is quarantined into `bad_rows`, same as the
+/// original two-pass scan did.
+fn validate_row(
+    idx: usize,
+    start: usize,
+    end: usize,
+    data: &[u8],
+    body_rdr: &mut csv::Reader<Cursor<&[u8]>>,
+    record: &mut csv::StringRecord,
+    expected_cols: usize,
+    good_row_indices: &mut Vec<usize>,
+    bad_rows: &mut Vec<(usize, String)>,
+) {
+    let ok = body_rdr.read_record(record).unwrap_or(false);
+    if ok && record.len() == expected_cols {
+        good_row_indices.push(start);
+    } else {
+        let raw_line = String::from_utf8_lossy(&data[start..end])
+            .trim_end_matches(['\r', '\n'])
+            .to_string();
+        bad_rows.push((idx, raw_line));
+    }
+}
+
+/// Number of leading lines (header included) sampled to build the character-column "ink
+/// histogram" used to guess fixed-width field boundaries.
+const FIXED_WIDTH_SAMPLE_LINES: usize = 50;
+/// Below this many sampled lines the histogram is too noisy to trust, so we fall back to
+/// treating each line as a single column instead of guessing bad gaps.
+const FIXED_WIDTH_MIN_SAMPLE_LINES: usize = 3;
+
+/// Parse space-aligned input (e.g. `ps`/`df`-style dumps) rather than comma- or tab-delimited
+/// input. Field boundaries are guessed with a histogram: for each character-column position,
+/// count how many of the first [`FIXED_WIDTH_SAMPLE_LINES`] lines (header plus data, so a short
+/// value under a wide header still gets its own field) have a non-whitespace character there.
+/// Positions that are whitespace across every sampled line are separators; contiguous separator
+/// positions collapse into a gap, and the contiguous "ink" positions between gaps become field
+/// ranges. Too few sampled lines to trust the histogram falls back to one whole-line column.
+/// Every line is then sliced and trimmed at those ranges, re-serialized as CSV, and handed to
+/// [`parse_csv`] so fixed-width input becomes a normal typed `DataFrame`.
+pub fn parse_fixed_width<F: FnMut(usize, usize)>(data: &[u8], progress: Option<F>) -> Result<DataFrame, Box<dyn std::error::Error>> {
+    let text = String::from_utf8_lossy(data);
+    let lines: Vec<Vec<char>> = text.lines().map(|l| l.chars().collect()).collect();
+    if lines.is_empty() {
+        return Err("Empty input".into());
+    }
+
+    let sample_len = std::cmp::min(lines.len(), FIXED_WIDTH_SAMPLE_LINES);
+    let ranges = if sample_len < FIXED_WIDTH_MIN_SAMPLE_LINES {
+        let max_len = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+        vec![(0, max_len)]
+    } else {
+        guess_field_ranges(&lines[..sample_len])
+    };
+
+    let mut csv_buf: Vec<u8> = Vec::new();
+    {
+        let mut writer = csv::WriterBuilder::new().from_writer(&mut csv_buf);
+        for line in &lines {
+            let fields: Vec<String> = ranges.iter()
+                .map(|&(start, end)| slice_chars(line, start, end).trim().to_string())
+                .collect();
+            writer.write_record(&fields)?;
+        }
+        writer.flush()?;
+    }
+
+    parse_csv(&csv_buf, progress)
+}
+
+/// Build the ink histogram over `sample` and collapse it into `(start, end)` character-index
+/// field ranges (end exclusive).
+fn guess_field_ranges(sample: &[Vec<char>]) -> Vec<(usize, usize)> {
+    let max_len = sample.iter().map(|l| l.len()).max().unwrap_or(0);
+    let mut ink = vec![0usize; max_len];
+    for line in sample {
+        for (i, &c) in line.iter().enumerate() {
+            if !c.is_whitespace() {
+                ink[i] += 1;
+            }
+        }
+    }
+
+    let mut ranges = Vec::new();
+    let mut field_start: Option<usize> = None;
+    for i in 0..max_len {
+        if ink[i] > 0 {
+            if field_start.is_none() {
+                field_start = Some(i);
+            }
+        } else if let Some(start) = field_start.take() {
+            ranges.push((start, i));
+        }
+    }
+    if let Some(start) = field_start {
+        ranges.push((start, max_len));
+    }
+
+    if ranges.is_empty() {
+        ranges.push((0, max_len));
+    }
+    ranges
+}
+
+/// Slice a char buffer at `[start, end)`, clamping `end` to the line's length (shorter lines
+/// just contribute an empty/partial field for trailing columns).
+fn slice_chars(line: &[char], start: usize, end: usize) -> String {
+    let end = std::cmp::min(end, line.len());
+    if start >= end {
+        return String::new();
+    }
+    line[start..end].iter().collect()
+}
+
 /// Sample values to guess the type
 fn infer_column_type(sample: &[String]) -> ColumnType {
     // We check against these types in order of specificity
@@ -43,26 +43,230 @@ pub fn is_email_like(s: &str) -> bool {
     !t.is_empty() && EMAIL_DETECT.is_match(t)
 }
 
-/// Normalize email per RFC 5322 principles: remove duplicate @ (keep first only), trim, lowercase domain.
-/// Returns None if result is not valid per email_address crate (RFC-compliant).
-pub fn normalize_email(s: &str) -> Option<String> {
-    let repaired = email_remove_duplicate_at(s);
-    let t = repaired.trim();
+/// A parsed RFC 5322 address: local-part, domain, and any separated display name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedEmail {
+    pub local: String,
+    pub domain: String,
+    pub display_name: Option<String>,
+}
+
+/// Parse an RFC 5322 address: `[display-name] ["<"] local "@" domain [">"]`, where `local` is a
+/// dot-atom or a quoted-string and `domain` is a dot-atom or a bracketed domain-literal (`[...]`).
+/// Strips `(...)` comments anywhere in the input and decodes MIME encoded-words
+/// (`=?charset?B/Q?...?=`) found in the display name. Returns `None` if the shape doesn't match
+/// (no `@`, unterminated quote/angle-bracket, empty local-part or domain).
+pub fn parse_rfc5322(s: &str) -> Option<ParsedEmail> {
+    let no_comments = strip_comments(s.trim());
+    let t = no_comments.trim();
     if t.is_empty() {
         return None;
     }
-    let parts: Vec<&str> = t.splitn(2, '@').collect();
-    if parts.len() != 2 {
+
+    // addr-spec may be wrapped in "<...>" with a display-name in front of it.
+    let (display_raw, addr_part) = if let Some(open) = t.find('<') {
+        let close = t.rfind('>')?;
+        if close <= open {
+            return None;
+        }
+        (Some(t[..open].trim()), t[open + 1..close].trim())
+    } else {
+        (None, t)
+    };
+
+    let display_name = display_raw
+        .map(|d| d.trim_matches('"').trim())
+        .filter(|d| !d.is_empty())
+        .map(decode_mime_encoded_words);
+
+    // local-part: a quoted-string up to its matching unescaped quote, or a dot-atom up to '@'.
+    let (local, rest) = if let Some(unquoted) = addr_part.strip_prefix('"') {
+        let mut end = None;
+        let mut escaped = false;
+        for (i, c) in unquoted.char_indices() {
+            if escaped {
+                escaped = false;
+                continue;
+            }
+            match c {
+                '\\' => escaped = true,
+                '"' => {
+                    end = Some(i);
+                    break;
+                }
+                _ => {}
+            }
+        }
+        let end = end?;
+        (addr_part[..end + 2].to_string(), &addr_part[end + 2..])
+    } else {
+        let at = addr_part.find('@')?;
+        (addr_part[..at].to_string(), &addr_part[at..])
+    };
+
+    let rest = rest.trim();
+    let domain_part = rest.strip_prefix('@')?.trim();
+    if domain_part.is_empty() || local.is_empty() {
         return None;
     }
-    let local = parts[0].trim();
-    let domain = parts[1].trim().to_lowercase();
-    if local.is_empty() || domain.is_empty() {
+
+    let domain = if domain_part.starts_with('[') && domain_part.ends_with(']') {
+        domain_part.to_string()
+    } else {
+        domain_part.to_lowercase()
+    };
+
+    Some(ParsedEmail { local, domain, display_name })
+}
+
+/// Remove RFC 5322 `(...)` comments. Comments nest (a `(` inside a comment opens a sub-comment
+/// that needs its own `)`), with minimal backslash-escape handling.
+fn strip_comments(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut depth = 0u32;
+    let mut escaped = false;
+    for c in s.chars() {
+        if depth > 0 {
+            if escaped {
+                escaped = false;
+            } else {
+                match c {
+                    '\\' => escaped = true,
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => {}
+                }
+            }
+            continue;
+        }
+        if c == '(' {
+            depth += 1;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Decode MIME encoded-words (`=?charset?B?base64?=` / `=?charset?Q?quoted-printable?=`), e.g.
+/// in a display name. Unrecognized or malformed words are left untouched.
+fn decode_mime_encoded_words(s: &str) -> String {
+    let mut out = String::new();
+    let mut rest = s;
+    while let Some(start) = rest.find("=?") {
+        out.push_str(&rest[..start]);
+        let tail = &rest[start + 2..];
+        match decode_one_encoded_word(tail) {
+            Some((decoded, consumed)) => {
+                out.push_str(&decoded);
+                rest = &tail[consumed..];
+            }
+            None => {
+                out.push_str("=?");
+                rest = tail;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Decode one `charset?encoding?text?=` body immediately following a consumed `"=?"`. Returns
+/// the decoded text and how many bytes of `tail` it consumed.
+fn decode_one_encoded_word(tail: &str) -> Option<(String, usize)> {
+    let q1 = tail.find('?')?;
+    let charset = &tail[..q1];
+    let after_charset = &tail[q1 + 1..];
+    let q2 = after_charset.find('?')?;
+    let encoding = &after_charset[..q2];
+    let after_encoding = &after_charset[q2 + 1..];
+    let end = after_encoding.find("?=")?;
+    let text = &after_encoding[..end];
+
+    let bytes = match encoding.to_ascii_uppercase().as_str() {
+        "B" => base64_decode(text)?,
+        "Q" => quoted_printable_decode(text),
+        _ => return None,
+    };
+    // Only UTF-8 (and its us-ascii/latin1-compatible subset) is decoded; other charsets fall
+    // back to a lossy UTF-8 read rather than failing the whole normalization.
+    let _ = charset;
+    let consumed = q1 + 1 + q2 + 1 + end + 2;
+    Some((String::from_utf8_lossy(&bytes).into_owned(), consumed))
+}
+
+fn base64_decode(s: &str) -> Option<Vec<u8>> {
+    const TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut reverse = [255u8; 256];
+    for (i, &b) in TABLE.iter().enumerate() {
+        reverse[b as usize] = i as u8;
+    }
+
+    let mut out = Vec::with_capacity(s.len() / 4 * 3);
+    let mut buf = 0u32;
+    let mut bits = 0u32;
+    for b in s.bytes().filter(|&b| !b.is_ascii_whitespace()) {
+        if b == b'=' {
+            break;
+        }
+        let v = reverse[b as usize];
+        if v == 255 {
+            return None;
+        }
+        buf = (buf << 6) | v as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buf >> bits) as u8);
+        }
+    }
+    Some(out)
+}
+
+fn quoted_printable_decode(s: &str) -> Vec<u8> {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'_' => {
+                out.push(b' '); // Q-encoding-specific: '_' represents a space
+                i += 1;
+            }
+            b'=' if i + 3 <= bytes.len() && bytes[i + 1].is_ascii_hexdigit() && bytes[i + 2].is_ascii_hexdigit() => {
+                match u8::from_str_radix(&s[i + 1..i + 3], 16) {
+                    Ok(byte) => {
+                        out.push(byte);
+                        i += 3;
+                    }
+                    Err(_) => {
+                        out.push(bytes[i]);
+                        i += 1;
+                    }
+                }
+            }
+            b => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    out
+}
+
+/// Normalize per RFC 5322: parse the address (stripping comments, decoding MIME encoded-words in
+/// any display name), fold the domain to lowercase while preserving local-part case, and return
+/// the canonical `local@domain` — but only when that differs from the input and the result
+/// round-trips to a valid address (via the `email_address` crate), so genuinely malformed values
+/// are left for the caller to flag rather than silently rewritten.
+pub fn normalize_email(s: &str) -> Option<String> {
+    let parsed = parse_rfc5322(s)?;
+    let canonical = format!("{}@{}", parsed.local, parsed.domain);
+    if canonical == s.trim() {
         return None;
     }
-    let normalized = format!("{}@{}", local, domain);
-    if email_address::EmailAddress::from_str(&normalized).is_ok() {
-        Some(normalized)
+    if email_address::EmailAddress::from_str(&canonical).is_ok() {
+        Some(canonical)
     } else {
         None
     }
@@ -101,6 +305,45 @@ pub fn is_ipv4_like(s: &str) -> bool {
     t.split('.').all(|oct| oct.parse::<u8>().is_ok())
 }
 
+/// IPv6: full `x:x:x:x:x:x:x:x` form, `::` zero-compression, and optional embedded-IPv4 tails
+/// (`::ffff:192.168.0.1`). Rejects more than one `::`, more than 8 groups, or a hextet longer
+/// than 4 hex digits.
+pub fn is_ipv6_like(s: &str) -> bool {
+    let t = s.trim();
+    if t.is_empty() || !t.contains(':') || t.contains(":::") {
+        return false;
+    }
+    if t.matches("::").count() > 1 {
+        return false;
+    }
+
+    // An embedded IPv4 tail is equivalent to two 16-bit hextets.
+    let normalized = match t.rsplit_once(':') {
+        Some((head, tail)) if is_ipv4_like(tail) => format!("{}:0:0", head),
+        _ => t.to_string(),
+    };
+    let has_compression = normalized.contains("::");
+
+    let mut hextet_count = 0usize;
+    for side in normalized.splitn(2, "::") {
+        if side.is_empty() {
+            continue;
+        }
+        for hextet in side.split(':') {
+            if hextet.is_empty() || hextet.len() > 4 || !hextet.chars().all(|c| c.is_ascii_hexdigit()) {
+                return false;
+            }
+            hextet_count += 1;
+        }
+    }
+
+    if has_compression {
+        hextet_count < 8
+    } else {
+        hextet_count == 8
+    }
+}
+
 /// Mask email: j***@gmail.com (first char + *** + @ + domain)
 pub fn mask_email(s: &str) -> String {
     let t = s.trim();
@@ -139,3 +382,8 @@ pub fn redact_credit_card(s: &str) -> String {
 pub fn zero_ipv4(_s: &str) -> String {
     "0.0.0.0".to_string()
 }
+
+/// Zero-out IPv6: ::
+pub fn zero_ipv6(_s: &str) -> String {
+    "::".to_string()
+}
@@ -0,0 +1,97 @@
+//! HTML rendering for suggestion reports — a standalone, self-contained document a
+//! non-technical data owner can open in a browser to review normalizations before they're
+//! applied, mirroring the text/csv/html output selection of report-style CLIs.
+
+use super::mechanic::SuggestionReport;
+
+const STYLE: &str = "<style>\nbody{font-family:-apple-system,sans-serif;margin:2rem;color:#222}\nh1{margin-bottom:0.25rem}\nsection{margin-bottom:2rem}\ntable{border-collapse:collapse;width:100%}\nth,td{border:1px solid #ddd;padding:0.5rem;text-align:left;vertical-align:top}\nth{background:#f5f5f5}\nmark{background:#ffe4a3}\n.empty{color:#777;font-style:italic}\n</style>\n";
+
+/// Render one self-contained HTML document covering every column's suggestions: one `<section>`
+/// per column with a table of `description`, `affected_rows_count`, and a side-by-side
+/// `example_before`/`example_after` with the changed characters wrapped in `<mark>`.
+pub fn render_html(reports: &[(String, Vec<SuggestionReport>)]) -> String {
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>Suggestion Report</title>\n");
+    out.push_str(STYLE);
+    out.push_str("</head><body>\n<h1>Suggestion Report</h1>\n");
+
+    for (column_name, column_reports) in reports {
+        out.push_str("<section>\n<h2>");
+        out.push_str(&escape_html(column_name));
+        out.push_str("</h2>\n");
+
+        if column_reports.is_empty() {
+            out.push_str("<p class=\"empty\">No suggestions.</p>\n");
+        } else {
+            out.push_str("<table>\n<thead><tr><th>Description</th><th>Affected rows</th><th>Before</th><th>After</th></tr></thead>\n<tbody>\n");
+            for report in column_reports {
+                let (before, after) = diff_highlight(&report.example_before, &report.example_after);
+                out.push_str("<tr><td>");
+                out.push_str(&escape_html(&report.description));
+                out.push_str("</td><td>");
+                out.push_str(&report.affected_rows_count.to_string());
+                out.push_str("</td><td class=\"before\">");
+                out.push_str(&before);
+                out.push_str("</td><td class=\"after\">");
+                out.push_str(&after);
+                out.push_str("</td></tr>\n");
+            }
+            out.push_str("</tbody>\n</table>\n");
+        }
+        out.push_str("</section>\n");
+    }
+
+    out.push_str("</body></html>\n");
+    out
+}
+
+/// Escape the five HTML-significant characters.
+fn escape_html(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Split `before`/`after` into a common prefix, a changed middle, and a common suffix, and
+/// return each side HTML-escaped with its changed middle wrapped in `<mark>`.
+fn diff_highlight(before: &str, after: &str) -> (String, String) {
+    let b: Vec<char> = before.chars().collect();
+    let a: Vec<char> = after.chars().collect();
+
+    let mut prefix = 0;
+    while prefix < b.len() && prefix < a.len() && b[prefix] == a[prefix] {
+        prefix += 1;
+    }
+    let mut suffix = 0;
+    while suffix < b.len() - prefix && suffix < a.len() - prefix
+        && b[b.len() - 1 - suffix] == a[a.len() - 1 - suffix]
+    {
+        suffix += 1;
+    }
+
+    (highlight_middle(&b, prefix, suffix), highlight_middle(&a, prefix, suffix))
+}
+
+fn highlight_middle(chars: &[char], prefix: usize, suffix: usize) -> String {
+    let pre: String = chars[..prefix].iter().collect();
+    let mid: String = chars[prefix..chars.len() - suffix].iter().collect();
+    let post: String = chars[chars.len() - suffix..].iter().collect();
+
+    let mut out = escape_html(&pre);
+    if !mid.is_empty() {
+        out.push_str("<mark>");
+        out.push_str(&escape_html(&mid));
+        out.push_str("</mark>");
+    }
+    out.push_str(&escape_html(&post));
+    out
+}
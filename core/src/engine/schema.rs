@@ -2,6 +2,7 @@ use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 use regex::Regex;
 use lazy_static::lazy_static;
+use super::numfmt::{self, Locale};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Copy)]
 pub enum ColumnType {
@@ -16,6 +17,8 @@ pub enum ColumnType {
     Time,
     Currency,
     Percentage,
+    PhoneIntl,
+    Url,
 }
 
 impl Default for ColumnType {
@@ -27,6 +30,71 @@ impl Default for ColumnType {
 lazy_static! {
     static ref EMAIL_REGEX: Regex = Regex::new(r"(?i)^[a-z0-9._%+-]+@[a-z0-9.-]+\.[a-z]{2,}$").unwrap();
     static ref PHONE_US_REGEX: Regex = Regex::new(r"^\D*1?\D*([2-9][0-8][0-9])\D*([2-9][0-9]{2})\D*([0-9]{4})\D*$").unwrap();
+
+    /// Per-region mobile-number patterns, each paired with its E.164 calling code. A value is a
+    /// valid `PhoneIntl` if it matches ANY region here; `mechanic::normalize_phone_intl` picks a
+    /// specific region (the column's dominant one) to normalize towards.
+    pub static ref PHONE_INTL_REGIONS: Vec<PhoneRegion> = vec![
+        PhoneRegion::new("GB", "44", r"^(\+?44|0)7\d{9}$"),
+        PhoneRegion::new("DE", "49", r"^(\+?49|0)1\d{9,10}$"),
+        PhoneRegion::new("IN", "91", r"^(\+?91|0)?[6-9]\d{9}$"),
+        PhoneRegion::new("FR", "33", r"^(\+?33|0)[67]\d{8}$"),
+        PhoneRegion::new("ES", "34", r"^(\+?34)?[67]\d{8}$"),
+        PhoneRegion::new("IT", "39", r"^(\+?39)?3\d{9}$"),
+        PhoneRegion::new("NL", "31", r"^(\+?31|0)6\d{8}$"),
+        PhoneRegion::new("AU", "61", r"^(\+?61|0)4\d{8}$"),
+        PhoneRegion::new("JP", "81", r"^(\+?81|0)[789]0\d{8}$"),
+        PhoneRegion::new("CN", "86", r"^(\+?86)?1[3-9]\d{9}$"),
+        PhoneRegion::new("BR", "55", r"^(\+?55|0)?[1-9]{2}9\d{8}$"),
+        PhoneRegion::new("MX", "52", r"^(\+?52)?1?\d{10}$"),
+        PhoneRegion::new("ZA", "27", r"^(\+?27|0)[6-8]\d{8}$"),
+    ];
+
+    /// Full-string URL match: a dotted host ending in any alphabetic TLD after an `http(s)://`
+    /// scheme, or in a `COMMON_TLDS` entry when there is no scheme; optional port, optional
+    /// path/query/fragment.
+    static ref URL_REGEX: Regex = Regex::new(&format!(
+        r"(?i)^(https?://([a-z0-9]([a-z0-9-]{{0,61}}[a-z0-9])?\.)+[a-z]{{2,63}}|([a-z0-9]([a-z0-9-]{{0,61}}[a-z0-9])?\.)+({}))(:[0-9]{{1,5}})?(/[^\s<>]*)?$",
+        COMMON_TLDS.join("|")
+    )).unwrap();
+
+    /// Same host/TLD shape as `URL_REGEX` but unanchored, for locating a bare-domain URL entity
+    /// inside a longer string (see `mechanic::extract_url_entity`).
+    pub static ref BARE_HOST_REGEX: Regex = Regex::new(&format!(
+        r"(?i)\b([a-z0-9]([a-z0-9-]{{0,61}}[a-z0-9])?\.)+({})\b",
+        COMMON_TLDS.join("|")
+    )).unwrap();
+}
+
+/// Common TLDs recognized for bare-domain URL detection (no scheme present). A scheme already
+/// vouches for the value being a URL regardless of TLD, so this list only gates the no-scheme
+/// case; not exhaustive.
+pub const COMMON_TLDS: &[&str] = &[
+    "com", "org", "net", "edu", "gov", "mil", "io", "co", "us", "uk", "ca", "de", "fr", "jp",
+    "cn", "au", "info", "biz", "app", "dev", "ai", "me", "tv", "xyz", "online", "store", "tech",
+    "site", "blog",
+];
+
+/// URL: matches `URL_REGEX` after trimming surrounding whitespace.
+fn is_valid_url(value: &str) -> bool {
+    let s = value.trim();
+    if s.is_empty() {
+        return true;
+    }
+    URL_REGEX.is_match(s)
+}
+
+/// One region's mobile-number pattern, its ISO-ish code, and its E.164 calling code.
+pub struct PhoneRegion {
+    pub region: &'static str,
+    pub calling_code: &'static str,
+    pub regex: Regex,
+}
+
+impl PhoneRegion {
+    fn new(region: &'static str, calling_code: &'static str, pattern: &str) -> Self {
+        PhoneRegion { region, calling_code, regex: Regex::new(pattern).unwrap() }
+    }
 }
 
 impl ColumnType {
@@ -63,6 +131,11 @@ impl ColumnType {
             ColumnType::Time => is_valid_time(value),
             ColumnType::Currency => is_valid_currency(value),
             ColumnType::Percentage => is_valid_percentage(value),
+            ColumnType::PhoneIntl => {
+                let cleaned: String = value.chars().filter(|c| !matches!(c, ' ' | '-' | '(' | ')')).collect();
+                PHONE_INTL_REGIONS.iter().any(|r| r.regex.is_match(&cleaned))
+            }
+            ColumnType::Url => is_valid_url(value),
         }
     }
 }
@@ -124,12 +197,22 @@ fn is_valid_time(value: &str) -> bool {
     false
 }
 
-/// Currency: strip $€£, and spaces; parse as f64.
+/// Currency: parse as f64 under US conventions (`.` decimal, `,` grouping) after stripping $€£¥
+/// and `,`. Any value that `numfmt::detect_value_locale` reads as non-US is rejected first: a
+/// space or NBSP anywhere, a lone `.` followed by exactly three digits, a `,` after the last `.`,
+/// or a lone `,` that doesn't read as US-style thousands grouping (e.g. `"50,00"`, a
+/// German/French-style decimal comma). Such a value is rejected
+/// instead of silently stripped-and-parsed into a wildly wrong magnitude (`"50,00"` -> `5000`) —
+/// rejecting it here is what lets it land in `analyze_column`'s `invalid_values` so the
+/// locale-aware `NormalizeNumberFormat` suggestion can pick it up.
 fn is_valid_currency(value: &str) -> bool {
     let s = value.trim();
     if s.is_empty() {
         return true;
     }
+    if !matches!(numfmt::detect_value_locale(s), None | Some(Locale::EnUs)) {
+        return false;
+    }
     let stripped: String = s
         .chars()
         .filter(|c| !matches!(c, '$' | '€' | '£' | '¥' | ',' | ' '))
@@ -154,4 +237,9 @@ fn is_valid_percentage(value: &str) -> bool {
 pub struct ColumnSchema {
     pub name: String,
     pub detected_type: ColumnType,
+    /// Present for a derived column added via `DataFrame::add_computed_column`: the expression
+    /// evaluated per row in `get_cell`/`get_row`/`validate_column` instead of reading `raw_data`.
+    /// `None` for an ordinary parsed column.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub computed: Option<super::expr::Expr>,
 }
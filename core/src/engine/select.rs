@@ -0,0 +1,74 @@
+//! Column selection, mirroring qsv's `--select`: a comma-separated list of column names,
+//! 0-based indices, and `lo-hi` index ranges, optionally negated with a leading `!` to mean
+//! "every column except these". Used to scope bulk actions to a subset of columns instead of
+//! the whole sheet.
+
+use crate::engine::schema::ColumnSchema;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SelectorTerm {
+    Name(String),
+    Index(usize),
+    Range(usize, usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct ColumnSelector {
+    pub terms: Vec<SelectorTerm>,
+    pub negate: bool,
+}
+
+impl ColumnSelector {
+    /// Parse a qsv-style select expression: `"email,phone"`, `"2-5"`, `"!3,7-9"`.
+    pub fn parse(expr: &str) -> Result<ColumnSelector, String> {
+        let mut expr = expr.trim();
+        let negate = if let Some(rest) = expr.strip_prefix('!') {
+            expr = rest;
+            true
+        } else {
+            false
+        };
+
+        let mut terms = Vec::new();
+        for part in expr.split(',') {
+            let part = part.trim();
+            if part.is_empty() {
+                continue;
+            }
+            if let Some((lo, hi)) = part.split_once('-') {
+                if let (Ok(lo), Ok(hi)) = (lo.trim().parse::<usize>(), hi.trim().parse::<usize>()) {
+                    terms.push(SelectorTerm::Range(lo, hi));
+                    continue;
+                }
+            }
+            if let Ok(idx) = part.parse::<usize>() {
+                terms.push(SelectorTerm::Index(idx));
+            } else {
+                terms.push(SelectorTerm::Name(part.to_string()));
+            }
+        }
+        if terms.is_empty() {
+            return Err("column selector is empty".to_string());
+        }
+        Ok(ColumnSelector { terms, negate })
+    }
+
+    /// Resolve against `columns`, returning the matching 0-based column indices in column order
+    /// (not selector-term order), honoring `negate`.
+    pub fn resolve(&self, columns: &[ColumnSchema]) -> Vec<usize> {
+        let matches = |idx: usize, col: &ColumnSchema| {
+            self.terms.iter().any(|term| match term {
+                SelectorTerm::Name(name) => &col.name == name,
+                SelectorTerm::Index(i) => *i == idx,
+                SelectorTerm::Range(lo, hi) => idx >= *lo && idx <= *hi,
+            })
+        };
+
+        columns
+            .iter()
+            .enumerate()
+            .filter(|(idx, col)| matches(*idx, col) != self.negate)
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+}
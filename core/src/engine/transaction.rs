@@ -0,0 +1,102 @@
+//! Staged transactions: accumulate cell changes in a scratch patch map instead of
+//! `DataFrame::patches`, so a preview can be inspected (and thrown away) before anything is
+//! written to the dataset the undo/redo history and `validate_*` calls see.
+
+use std::collections::HashMap;
+use serde::Serialize;
+use super::dataframe::DataFrame;
+
+/// One `{row, col, old, new}` line of a transaction preview. `old` is `None` if the cell had no
+/// patch and wasn't readable (out of bounds), matching `DataFrame::get_cell`.
+#[derive(Serialize)]
+pub struct CellDiff {
+    pub row: usize,
+    pub col: usize,
+    pub old: Option<String>,
+    pub new: String,
+}
+
+/// `preview_transaction`'s result: a capped sample of diffs plus the true total affected count,
+/// since a staged regex/suggestion run can touch far more cells than are worth shipping to JS.
+#[derive(Serialize)]
+pub struct TransactionPreview {
+    pub diffs: Vec<CellDiff>,
+    pub total_affected: usize,
+}
+
+/// A staged set of cell changes, kept apart from `df.patches` until `commit`. Shaped like
+/// `DataFrame::patches` (`row_idx -> col_idx -> new_value`) so merging it in on commit is a
+/// straight walk, not a translation.
+#[derive(Debug, Default)]
+pub struct Transaction {
+    scratch: HashMap<usize, HashMap<usize, String>>,
+}
+
+impl Transaction {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The value `(row_idx, col_idx)` would read as under this transaction: the staged value if
+    /// one's been set, else whatever `df` has right now (committed patch or raw cell). Lets a
+    /// second `apply_*` call in the same transaction see the first one's edits.
+    pub fn get_cell(&self, df: &DataFrame, row_idx: usize, col_idx: usize) -> Option<String> {
+        if let Some(val) = self.scratch.get(&row_idx).and_then(|m| m.get(&col_idx)) {
+            return Some(val.clone());
+        }
+        df.get_cell(row_idx, col_idx)
+    }
+
+    /// Stage a cell change. Does not touch `df` in any way.
+    pub fn set_cell(&mut self, row_idx: usize, col_idx: usize, value: String) {
+        self.scratch.entry(row_idx).or_insert_with(HashMap::new).insert(col_idx, value);
+    }
+
+    /// Total number of cells staged across every column.
+    pub fn total_affected(&self) -> usize {
+        self.scratch.values().map(|m| m.len()).sum()
+    }
+
+    /// Sample up to `limit` `{row, col, old, new}` diffs plus the true total affected count.
+    /// `old` is read fresh from `df` rather than cached at stage time, so it reflects any change
+    /// made to `df.patches` since the edit was staged.
+    pub fn preview(&self, df: &DataFrame, limit: usize) -> TransactionPreview {
+        let mut diffs = Vec::new();
+        'outer: for (&row_idx, cols) in &self.scratch {
+            for (&col_idx, new_val) in cols {
+                if diffs.len() >= limit {
+                    break 'outer;
+                }
+                diffs.push(CellDiff {
+                    row: row_idx,
+                    col: col_idx,
+                    old: df.get_cell(row_idx, col_idx),
+                    new: new_val.clone(),
+                });
+            }
+        }
+        TransactionPreview { diffs, total_affected: self.total_affected() }
+    }
+
+    /// Merge every staged change into `df.patches` and record one undo/redo entry per touched
+    /// column (same granularity as `apply_suggestion`/`apply_bulk_action`), atomically from the
+    /// caller's perspective: either this runs to completion or (on abort) never runs at all.
+    /// Returns the number of cells written. Consumes `self`.
+    pub fn commit(self, df: &mut DataFrame, label: &str) -> usize {
+        let mut prior_by_col: HashMap<usize, Vec<(usize, Option<String>)>> = HashMap::new();
+        let mut count = 0;
+        for (row_idx, cols) in self.scratch {
+            for (col_idx, new_val) in cols {
+                let prior_val = df.patches.get(&row_idx).and_then(|m| m.get(&col_idx)).cloned();
+                df.patches.entry(row_idx).or_insert_with(HashMap::new).insert(col_idx, new_val);
+                prior_by_col.entry(col_idx).or_insert_with(Vec::new).push((row_idx, prior_val));
+                count += 1;
+            }
+        }
+        for (col_idx, prior) in prior_by_col {
+            df.record_operation(label.to_string(), col_idx, prior);
+            df.invalidate_index(col_idx);
+        }
+        count
+    }
+}
@@ -5,11 +5,16 @@ use lazy_static::lazy_static;
 use std::collections::HashMap;
 
 mod engine;
-use engine::{dataframe::DataFrame, parser::parse_csv, schema::{ColumnType, ColumnSchema}, mechanic, pii, bulk};
+use engine::{dataframe::{DataFrame, validate_computed_columns}, parser::{parse_csv, parse_csv_batched, parse_fixed_width, DEFAULT_BATCH_ROWS}, schema::{ColumnType, ColumnSchema}, mechanic, pii, bulk, report, numfmt, export, select::ColumnSelector, transaction::Transaction};
 
 // GLOBAL STATE (The "Database" in Memory)
 lazy_static! {
     static ref DATASET: Mutex<Option<DataFrame>> = Mutex::new(None);
+    /// Open staged transactions (see `begin_transaction`), keyed by handle. A transaction's
+    /// scratch patch map lives here, not on `DataFrame`, so `preview_transaction`/
+    /// `abort_transaction` never touch `df.patches` until `commit_transaction` merges it in.
+    static ref TRANSACTIONS: Mutex<HashMap<u64, Transaction>> = Mutex::new(HashMap::new());
+    static ref NEXT_TXN_HANDLE: Mutex<u64> = Mutex::new(1);
 }
 
 #[wasm_bindgen]
@@ -93,6 +98,84 @@ pub fn load_dataset_with_progress(data: &[u8], progress_fn: &js_sys::Function) -
     }
 }
 
+/// Like [`load_dataset_with_progress`], but also calls `on_batch_fn` with an early dataset
+/// summary after every `batch_rows` data rows are indexed and validated (`batch_rows <= 0` falls
+/// back to [`DEFAULT_BATCH_ROWS`]), so the UI can start rendering pages before a large upload is
+/// fully resident. See [`parse_csv_batched`] for the batching core.
+#[wasm_bindgen]
+pub fn load_dataset_batched(data: &[u8], batch_rows: usize, on_batch_fn: &js_sys::Function, progress_fn: &js_sys::Function) -> Result<JsValue, JsValue> {
+    log(&format!("🚀 Parsing {} bytes (batched)...", data.len()));
+    let batch_rows = if batch_rows == 0 { DEFAULT_BATCH_ROWS } else { batch_rows };
+
+    time("Rust: parse_csv_batched");
+    let mut progress_cb = |bytes: usize, total: usize| {
+        let _ = progress_fn.call2(
+            &JsValue::NULL,
+            &JsValue::from(bytes as f64),
+            &JsValue::from(total as f64),
+        );
+    };
+    let mut on_batch_cb = |df: &DataFrame| {
+        let summary = DatasetSummary {
+            row_count: df.rows,
+            columns: df.columns.clone(),
+            file_size_mb: data.len() as f64 / 1_048_576.0,
+        };
+        if let Ok(v) = serde_wasm_bindgen::to_value(&summary) {
+            let _ = on_batch_fn.call1(&JsValue::NULL, &v);
+        }
+    };
+
+    match parse_csv_batched(data, batch_rows, Some(&mut progress_cb), Some(&mut on_batch_cb), None) {
+        Ok(df) => {
+            timeEnd("Rust: parse_csv_batched");
+            let summary = DatasetSummary {
+                row_count: df.rows,
+                columns: df.columns.clone(),
+                file_size_mb: data.len() as f64 / 1_048_576.0,
+            };
+
+            let mut store = DATASET.lock().unwrap();
+            *store = Some(df);
+
+            Ok(serde_wasm_bindgen::to_value(&summary)?)
+        },
+        Err(e) => {
+            timeEnd("Rust: parse_csv_batched");
+            Err(JsValue::from_str(&format!("Parse error: {}", e)))
+        }
+    }
+}
+
+/// Load space-aligned (`ps`/`df`-style) input instead of delimited CSV. Field boundaries are
+/// guessed from a character-column histogram (see [`parse_fixed_width`]), then the rest of the
+/// pipeline (type inference, global state, summary) is identical to [`load_dataset`].
+#[wasm_bindgen]
+pub fn load_dataset_fixed_width(data: &[u8]) -> Result<JsValue, JsValue> {
+    log(&format!("🚀 Parsing {} bytes (fixed-width)...", data.len()));
+
+    time("Rust: parse_fixed_width");
+    match parse_fixed_width(data, Some(|_, _| {})) {
+        Ok(df) => {
+            timeEnd("Rust: parse_fixed_width");
+            let summary = DatasetSummary {
+                row_count: df.rows,
+                columns: df.columns.clone(),
+                file_size_mb: data.len() as f64 / 1_048_576.0,
+            };
+
+            let mut store = DATASET.lock().unwrap();
+            *store = Some(df);
+
+            Ok(serde_wasm_bindgen::to_value(&summary)?)
+        },
+        Err(e) => {
+            timeEnd("Rust: parse_fixed_width");
+            Err(JsValue::from_str(&format!("Parse error: {}", e)))
+        }
+    }
+}
+
 // NEW: Fetch a slice of rows for the Virtual Table
 #[wasm_bindgen]
 pub fn get_rows(start: usize, limit: usize) -> Result<JsValue, JsValue> {
@@ -122,8 +205,8 @@ pub fn get_rows(start: usize, limit: usize) -> Result<JsValue, JsValue> {
 
 #[wasm_bindgen]
 pub fn validate_chunk(start_row: usize, limit: usize) -> Result<JsValue, JsValue> {
-    let store = DATASET.lock().unwrap();
-    if let Some(df) = &*store {
+    let mut store = DATASET.lock().unwrap();
+    if let Some(df) = store.as_mut() {
         // Delegate to DataFrame's optimized zero-copy validator
         let error_flat_list = df.validate_range(start_row, limit);
         
@@ -148,6 +231,25 @@ pub fn get_suggestions(col_idx: usize) -> Result<JsValue, JsValue> {
     }
 }
 
+/// Render suggestions for every column as one self-contained HTML report, for review in a
+/// browser before applying. Mirrors `get_suggestions`, but across all columns and rendered
+/// as HTML instead of the serde/JSON shape.
+#[wasm_bindgen]
+pub fn get_suggestions_report_html() -> Result<String, JsValue> {
+    let store = DATASET.lock().unwrap();
+    if let Some(df) = &*store {
+        let reports: Vec<(String, Vec<mechanic::SuggestionReport>)> = df
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(col_idx, col)| (col.name.clone(), mechanic::analyze_column(df, col_idx)))
+            .collect();
+        Ok(report::render_html(&reports))
+    } else {
+        Err(JsValue::from_str("No dataset loaded"))
+    }
+}
+
 #[wasm_bindgen]
 pub fn apply_suggestion(col_idx: usize, suggestion_json: JsValue) -> Result<usize, JsValue> {
     let suggestion: mechanic::Suggestion = serde_wasm_bindgen::from_value(suggestion_json)?;
@@ -159,107 +261,42 @@ pub fn apply_suggestion(col_idx: usize, suggestion_json: JsValue) -> Result<usiz
             return Err(JsValue::from_str("Column index out of bounds"));
         }
         let mut fixed_count = 0;
+        let mut prior: Vec<(usize, Option<String>)> = Vec::new();
         let col_type = df.columns[col_idx].detected_type;
 
-        // This is inefficient as it iterates all rows.
-        // A better approach would be to iterate only the invalid rows, which we'd need to find first.
-        for row_idx in 0..df.rows {
+        // Suggestions gated on "the cell isn't valid" only ever touch invalid rows, so scan the
+        // cached invalid-row index instead of every row. The always-apply normalizers (fuzzy
+        // match, E.164, etc.) and the PII redactions legitimately touch valid cells too, so those
+        // still need the full column.
+        let validity_gated = !mechanic::is_always_apply_normalizer(&suggestion) && !mechanic::is_redaction_suggestion(&suggestion);
+        let row_scan: Box<dyn Iterator<Item = usize>> = if validity_gated {
+            let invalid = df
+                .invalid_rows(col_idx)
+                .cloned()
+                .unwrap_or_else(|| df.validate_column_fast(col_idx, col_type));
+            Box::new(invalid.into_iter())
+        } else {
+            Box::new(0..df.rows)
+        };
+
+        for row_idx in row_scan {
             if let Some(old_val) = df.get_cell(row_idx, col_idx) {
-                let (new_val, is_redaction) = match &suggestion {
-                    mechanic::Suggestion::TrimWhitespace => (old_val.trim().to_string(), false),
-                    mechanic::Suggestion::RemoveChars { chars } => (old_val.replace(chars, ""), false),
-                    mechanic::Suggestion::DigitsOnly => (old_val.chars().filter(|c| c.is_ascii_digit()).collect(), false),
-                    mechanic::Suggestion::PhoneStripToTenDigits => (mechanic::normalize_phone_to_ten_digits(&old_val), false),
-                    mechanic::Suggestion::NormalizeDateToIso => {
-                        let trimmed = old_val.trim();
-                        let v = if let Ok(d) = chrono::NaiveDate::parse_from_str(trimmed, "%m/%d/%Y") {
-                            d.format("%Y-%m-%d").to_string()
-                        } else {
-                            old_val.clone()
-                        };
-                        (v, false)
-                    },
-                    mechanic::Suggestion::NormalizeBooleanCase => (old_val.trim().to_lowercase(), false),
-                    mechanic::Suggestion::MaskEmail => (pii::mask_email(&old_val), true),
-                    mechanic::Suggestion::RedactSSN => (pii::redact_ssn(&old_val), true),
-                    mechanic::Suggestion::RedactCreditCard => (pii::redact_credit_card(&old_val), true),
-                    mechanic::Suggestion::ZeroIPv4 => (pii::zero_ipv4(&old_val), true),
-                    mechanic::Suggestion::NormalizeBooleanExtended => {
-                        let v = mechanic::normalize_boolean_extended(&old_val)
-                            .map(|s| s.to_string())
-                            .unwrap_or_else(|| old_val.clone());
-                        (v, false)
-                    },
-                    mechanic::Suggestion::NormalizeDateCascade => (mechanic::parse_date_cascade(&old_val), false),
-                    mechanic::Suggestion::FuzzyMatchCategorical { master_list, max_distance } => {
-                        let v = mechanic::fuzzy_match_best(&old_val, master_list, *max_distance)
-                            .unwrap_or_else(|| old_val.clone());
-                        (v, false)
-                    },
-                    mechanic::Suggestion::NormalizeEmail => {
-                        let v = pii::normalize_email(&old_val).unwrap_or_else(|| pii::email_remove_duplicate_at(&old_val));
-                        (v, false)
-                    },
-                    mechanic::Suggestion::NormalizePhoneE164 => (
-                        mechanic::normalize_phone_e164(&old_val).unwrap_or_else(|| old_val.clone()),
-                        false,
-                    ),
-                    mechanic::Suggestion::FormatPhoneUS => (
-                        mechanic::format_phone_us(&old_val).unwrap_or_else(|| old_val.clone()),
-                        false,
-                    ),
-                    mechanic::Suggestion::PadZipLeadingZeros => (
-                        mechanic::pad_zip_leading_zeros(&old_val).unwrap_or_else(|| old_val.clone()),
-                        false,
-                    ),
-                    mechanic::Suggestion::NormalizeStateAbbrev => (
-                        mechanic::normalize_state_abbrev(&old_val).unwrap_or_else(|| old_val.clone()),
-                        false,
-                    ),
-                    mechanic::Suggestion::NormalizeUuid => (mechanic::normalize_uuid(&old_val), false),
-                    mechanic::Suggestion::NormalizeTimeToIso => (
-                        mechanic::normalize_time_to_iso(&old_val).unwrap_or_else(|| old_val.clone()),
-                        false,
-                    ),
-                    mechanic::Suggestion::NormalizeCurrency => (mechanic::normalize_currency(&old_val), false),
-                    mechanic::Suggestion::NormalizePercentage => (mechanic::normalize_percentage(&old_val), false),
-                };
-
-                let should_apply = if is_redaction {
-                    let matches = match &suggestion {
-                        mechanic::Suggestion::MaskEmail => pii::is_email_like(&old_val),
-                        mechanic::Suggestion::RedactSSN => pii::is_ssn_like(&old_val),
-                        mechanic::Suggestion::RedactCreditCard => pii::looks_like_credit_card(&old_val),
-                        mechanic::Suggestion::ZeroIPv4 => pii::is_ipv4_like(&old_val),
-                        _ => false,
-                    };
-                    matches && new_val != old_val
-                } else if matches!(
-                    suggestion,
-                    mechanic::Suggestion::FuzzyMatchCategorical { .. }
-                        | mechanic::Suggestion::NormalizeEmail
-                        | mechanic::Suggestion::NormalizePhoneE164
-                        | mechanic::Suggestion::PadZipLeadingZeros
-                        | mechanic::Suggestion::NormalizeStateAbbrev
-                        | mechanic::Suggestion::NormalizeUuid
-                        | mechanic::Suggestion::NormalizeTimeToIso
-                        | mechanic::Suggestion::NormalizeCurrency
-                        | mechanic::Suggestion::NormalizePercentage
-                ) {
-                    new_val != old_val
-                } else {
-                    !col_type.is_valid(&old_val) && new_val != old_val && col_type.is_valid(&new_val)
-                };
+                let (new_val, _is_redaction) = mechanic::compute_suggestion_value(&suggestion, &old_val);
+                let should_apply = mechanic::should_apply_suggestion(&suggestion, &old_val, &new_val, col_type);
 
                 if should_apply {
+                    let prior_val = df.patches.get(&row_idx).and_then(|m| m.get(&col_idx)).cloned();
                     df.patches
                         .entry(row_idx)
                         .or_insert_with(HashMap::new)
                         .insert(col_idx, new_val);
+                    prior.push((row_idx, prior_val));
                     fixed_count += 1;
                 }
             }
         }
+        df.record_operation("apply_suggestion", col_idx, prior);
+        df.invalidate_index(col_idx);
         let ms = start.elapsed().as_millis();
         log(&format!("[apply_suggestion] col_idx={} rows={} count={} ms={}", col_idx, df.rows, fixed_count, ms));
         Ok(fixed_count)
@@ -268,8 +305,95 @@ pub fn apply_suggestion(col_idx: usize, suggestion_json: JsValue) -> Result<usiz
     }
 }
 
+/// Row cadence for progress callbacks in the `_with_progress` transform variants: frequent enough
+/// for a smooth progress bar, infrequent enough not to cross the JS boundary on every row.
+const PROGRESS_ROW_INTERVAL: usize = 64 * 1024;
+
+/// Like [`apply_suggestion`], but calls `progress_fn(rows_processed, total, changed_so_far)`
+/// every [`PROGRESS_ROW_INTERVAL`] rows and checks `cancel_fn()` at the same cadence; a truthy
+/// return aborts the scan early, committing the patches already made and returning the partial
+/// count. Lets the worker drive a progress bar and stop a runaway transform mid-flight instead of
+/// freezing the tab (see `find_replace_range`'s note on hitting "unreachable" on large datasets).
 #[wasm_bindgen]
-pub fn apply_bulk_action(col_idx: usize, action_json: JsValue) -> Result<usize, JsValue> {
+pub fn apply_suggestion_with_progress(
+    col_idx: usize,
+    suggestion_json: JsValue,
+    progress_fn: &js_sys::Function,
+    cancel_fn: &js_sys::Function,
+) -> Result<usize, JsValue> {
+    let suggestion: mechanic::Suggestion = serde_wasm_bindgen::from_value(suggestion_json)?;
+    let mut store = DATASET.lock().unwrap();
+    let start = Instant::now();
+
+    if let Some(df) = store.as_mut() {
+        if col_idx >= df.columns.len() {
+            return Err(JsValue::from_str("Column index out of bounds"));
+        }
+        let col_type = df.columns[col_idx].detected_type;
+        let validity_gated = !mechanic::is_always_apply_normalizer(&suggestion) && !mechanic::is_redaction_suggestion(&suggestion);
+        let row_scan: Vec<usize> = if validity_gated {
+            df.invalid_rows(col_idx).cloned().unwrap_or_else(|| df.validate_column_fast(col_idx, col_type))
+        } else {
+            (0..df.rows).collect()
+        };
+        let total = row_scan.len();
+
+        let mut fixed_count = 0;
+        let mut prior: Vec<(usize, Option<String>)> = Vec::new();
+        let mut cancelled = false;
+        for (processed, row_idx) in row_scan.into_iter().enumerate() {
+            if let Some(old_val) = df.get_cell(row_idx, col_idx) {
+                let (new_val, _is_redaction) = mechanic::compute_suggestion_value(&suggestion, &old_val);
+                if mechanic::should_apply_suggestion(&suggestion, &old_val, &new_val, col_type) {
+                    let prior_val = df.patches.get(&row_idx).and_then(|m| m.get(&col_idx)).cloned();
+                    df.patches
+                        .entry(row_idx)
+                        .or_insert_with(HashMap::new)
+                        .insert(col_idx, new_val);
+                    prior.push((row_idx, prior_val));
+                    fixed_count += 1;
+                }
+            }
+
+            let rows_processed = processed + 1;
+            if rows_processed % PROGRESS_ROW_INTERVAL == 0 || rows_processed == total {
+                let _ = progress_fn.call3(
+                    &JsValue::NULL,
+                    &JsValue::from(rows_processed as f64),
+                    &JsValue::from(total as f64),
+                    &JsValue::from(fixed_count as f64),
+                );
+                let should_cancel = cancel_fn.call0(&JsValue::NULL).map(|v| v.is_truthy()).unwrap_or(false);
+                if should_cancel {
+                    cancelled = true;
+                    break;
+                }
+            }
+        }
+        let label = if cancelled { "apply_suggestion:cancelled" } else { "apply_suggestion" };
+        df.record_operation(label, col_idx, prior);
+        df.invalidate_index(col_idx);
+        let ms = start.elapsed().as_millis();
+        log(&format!(
+            "[apply_suggestion_with_progress] col_idx={} rows={} count={} cancelled={} ms={}",
+            col_idx, df.rows, fixed_count, cancelled, ms
+        ));
+        Ok(fixed_count)
+    } else {
+        Err(JsValue::from_str("No dataset loaded"))
+    }
+}
+
+/// Cell-changed count plus total substitution count for a bulk action, so the UI can report a
+/// qsv-style "N replacements across M cells" summary and know whether anything actually changed.
+#[derive(serde::Serialize)]
+pub struct BulkActionResult {
+    pub changed_cells: usize,
+    pub replacements: usize,
+}
+
+#[wasm_bindgen]
+pub fn apply_bulk_action(col_idx: usize, action_json: JsValue) -> Result<JsValue, JsValue> {
     let action: bulk::BulkAction = serde_wasm_bindgen::from_value(action_json)
         .map_err(|e| JsValue::from_str(&format!("Invalid bulk action: {}", e)))?;
 
@@ -284,25 +408,130 @@ pub fn apply_bulk_action(col_idx: usize, action_json: JsValue) -> Result<usize,
             return Err(JsValue::from_str("Column index out of bounds"));
         }
         let mut changed_count = 0;
+        let mut replacement_count = 0;
+        let mut prior: Vec<(usize, Option<String>)> = Vec::new();
         for row_idx in 0..df.rows {
             if let Some(old_val) = df.get_cell(row_idx, col_idx) {
-                match bulk::apply_to_cell(&old_val, &action, compiled_regex.as_ref()) {
-                    Ok(new_val) => {
+                match bulk::apply_to_cell_counted(&old_val, &action, compiled_regex.as_ref()) {
+                    Ok((new_val, count)) => {
+                        replacement_count += count;
+                        if new_val != old_val {
+                            let prior_val = df.patches.get(&row_idx).and_then(|m| m.get(&col_idx)).cloned();
+                            df.patches
+                                .entry(row_idx)
+                                .or_insert_with(HashMap::new)
+                                .insert(col_idx, new_val);
+                            prior.push((row_idx, prior_val));
+                            changed_count += 1;
+                        }
+                    }
+                    Err(e) => return Err(JsValue::from_str(&format!("Regex error: {}", e))),
+                }
+            }
+        }
+        df.record_operation("apply_bulk_action", col_idx, prior);
+        df.invalidate_index(col_idx);
+        let ms = start.elapsed().as_millis();
+        log(&format!("[apply_bulk_action] col_idx={} rows={} changed={} replacements={} ms={}", col_idx, df.rows, changed_count, replacement_count, ms));
+        Ok(serde_wasm_bindgen::to_value(&BulkActionResult { changed_cells: changed_count, replacements: replacement_count })?)
+    } else {
+        Err(JsValue::from_str("No dataset loaded"))
+    }
+}
+
+/// Like [`apply_bulk_action`], but calls `progress_fn(rows_processed, total, changed_so_far)`
+/// every [`PROGRESS_ROW_INTERVAL`] rows and checks `cancel_fn()` at the same cadence; a truthy
+/// return aborts the scan early, committing the patches already made and returning the partial
+/// result. See `apply_suggestion_with_progress` for the same pattern on suggestions.
+#[wasm_bindgen]
+pub fn apply_bulk_action_with_progress(
+    col_idx: usize,
+    action_json: JsValue,
+    progress_fn: &js_sys::Function,
+    cancel_fn: &js_sys::Function,
+) -> Result<JsValue, JsValue> {
+    let action: bulk::BulkAction = serde_wasm_bindgen::from_value(action_json)
+        .map_err(|e| JsValue::from_str(&format!("Invalid bulk action: {}", e)))?;
+    let compiled_regex = bulk::compile_regex_for_action(&action)
+        .map_err(|e| JsValue::from_str(&format!("Invalid regex: {}", e)))?;
+
+    let mut store = DATASET.lock().unwrap();
+    let start = Instant::now();
+    if let Some(df) = store.as_mut() {
+        if col_idx >= df.columns.len() {
+            return Err(JsValue::from_str("Column index out of bounds"));
+        }
+        let total = df.rows;
+        let mut changed_count = 0;
+        let mut replacement_count = 0;
+        let mut prior: Vec<(usize, Option<String>)> = Vec::new();
+        let mut cancelled = false;
+        for row_idx in 0..total {
+            if let Some(old_val) = df.get_cell(row_idx, col_idx) {
+                match bulk::apply_to_cell_counted(&old_val, &action, compiled_regex.as_ref()) {
+                    Ok((new_val, count)) => {
+                        replacement_count += count;
                         if new_val != old_val {
+                            let prior_val = df.patches.get(&row_idx).and_then(|m| m.get(&col_idx)).cloned();
                             df.patches
                                 .entry(row_idx)
                                 .or_insert_with(HashMap::new)
                                 .insert(col_idx, new_val);
+                            prior.push((row_idx, prior_val));
                             changed_count += 1;
                         }
                     }
                     Err(e) => return Err(JsValue::from_str(&format!("Regex error: {}", e))),
                 }
             }
+
+            let rows_processed = row_idx + 1;
+            if rows_processed % PROGRESS_ROW_INTERVAL == 0 || rows_processed == total {
+                let _ = progress_fn.call3(
+                    &JsValue::NULL,
+                    &JsValue::from(rows_processed as f64),
+                    &JsValue::from(total as f64),
+                    &JsValue::from(changed_count as f64),
+                );
+                let should_cancel = cancel_fn.call0(&JsValue::NULL).map(|v| v.is_truthy()).unwrap_or(false);
+                if should_cancel {
+                    cancelled = true;
+                    break;
+                }
+            }
         }
+        let label = if cancelled { "apply_bulk_action:cancelled" } else { "apply_bulk_action" };
+        df.record_operation(label, col_idx, prior);
+        df.invalidate_index(col_idx);
         let ms = start.elapsed().as_millis();
-        log(&format!("[apply_bulk_action] col_idx={} rows={} count={} ms={}", col_idx, df.rows, changed_count, ms));
-        Ok(changed_count)
+        log(&format!(
+            "[apply_bulk_action_with_progress] col_idx={} rows={} changed={} replacements={} cancelled={} ms={}",
+            col_idx, df.rows, changed_count, replacement_count, cancelled, ms
+        ));
+        Ok(serde_wasm_bindgen::to_value(&BulkActionResult { changed_cells: changed_count, replacements: replacement_count })?)
+    } else {
+        Err(JsValue::from_str("No dataset loaded"))
+    }
+}
+
+/// Like `apply_bulk_action`, but scoped to the columns `selector_expr` picks out (qsv `--select`
+/// syntax: comma-separated names/indices/ranges, optional leading `!` to negate) instead of a
+/// single `col_idx`. Returns per-column replacement counts.
+#[wasm_bindgen]
+pub fn apply_bulk_action_selected(action_json: JsValue, selector_expr: &str) -> Result<JsValue, JsValue> {
+    let action: bulk::BulkAction = serde_wasm_bindgen::from_value(action_json)
+        .map_err(|e| JsValue::from_str(&format!("Invalid bulk action: {}", e)))?;
+    let selector = ColumnSelector::parse(selector_expr)
+        .map_err(|e| JsValue::from_str(&format!("Invalid column selector: {}", e)))?;
+
+    let mut store = DATASET.lock().unwrap();
+    let start = Instant::now();
+    if let Some(df) = store.as_mut() {
+        let replacements_by_col = bulk::apply_bulk_action(df, &action, &selector)
+            .map_err(|e| JsValue::from_str(&format!("Regex error: {}", e)))?;
+        let ms = start.elapsed().as_millis();
+        log(&format!("[apply_bulk_action_selected] selector={} columns_touched={} ms={}", selector_expr, replacements_by_col.len(), ms));
+        Ok(serde_wasm_bindgen::to_value(&replacements_by_col)?)
     } else {
         Err(JsValue::from_str("No dataset loaded"))
     }
@@ -314,37 +543,39 @@ pub fn apply_correction(col_idx: usize, strategy: &str) -> Result<usize, JsValue
     let start = Instant::now();
 
     if let Some(df) = store.as_mut() {
+        if col_idx >= df.columns.len() {
+            return Err(JsValue::from_str("Column index out of bounds"));
+        }
         let mut fixed_count = 0;
         let col_type = df.columns[col_idx].detected_type;
 
-        // We need to collect indices first to avoid borrowing conflict (mutable borrow of patches vs immutable borrow for get_cell)
-        // Actually, get_cell borrows self immutably. modifying patches borrows self mutably.
-        // So we must do this in two passes or be clever.
-        // Pass 1: Find invalid rows
-        let mut invalid_rows = Vec::new();
-        for row_idx in 0..df.rows {
-            if let Some(val) = df.get_cell(row_idx, col_idx) {
-                if !col_type.is_valid(&val) {
-                    invalid_rows.push(row_idx);
-                }
-            }
-        }
+        // Both strategies only ever touch invalid cells, so pull the row set straight from the
+        // cached invalid-row index (scanning the column fresh only if it's not cached yet)
+        // instead of re-scanning every row to find them.
+        let invalid_rows = df
+            .invalid_rows(col_idx)
+            .cloned()
+            .unwrap_or_else(|| df.validate_column_fast(col_idx, col_type));
 
-        // Pass 2: Apply fixes
+        // Apply fixes
+        let mut prior: Vec<(usize, Option<String>)> = Vec::new();
         for row_idx in invalid_rows {
             match strategy {
                 "clear" => {
                     // Set to empty string
+                    let prior_val = df.patches.get(&row_idx).and_then(|m| m.get(&col_idx)).cloned();
                     df.patches
                         .entry(row_idx)
                         .or_insert_with(HashMap::new)
                         .insert(col_idx, "".to_string());
+                    prior.push((row_idx, prior_val));
                     fixed_count += 1;
                 },
                 "revert" => {
                     // Remove from patches (if exists)
                     if let Some(row_patches) = df.patches.get_mut(&row_idx) {
-                        if row_patches.remove(&col_idx).is_some() {
+                        if let Some(prior_val) = row_patches.remove(&col_idx) {
+                             prior.push((row_idx, Some(prior_val)));
                              fixed_count += 1;
                         }
                         // Clean up empty row map if needed? Not strictly necessary but good for memory.
@@ -356,6 +587,8 @@ pub fn apply_correction(col_idx: usize, strategy: &str) -> Result<usize, JsValue
                 _ => return Err(JsValue::from_str(&format!("Unknown strategy: {}", strategy))),
             }
         }
+        df.record_operation(format!("apply_correction:{}", strategy), col_idx, prior);
+        df.invalidate_index(col_idx);
 
         let ms = start.elapsed().as_millis();
         log(&format!("[apply_correction] col_idx={} strategy={} count={} ms={}", col_idx, strategy, fixed_count, ms));
@@ -365,6 +598,26 @@ pub fn apply_correction(col_idx: usize, strategy: &str) -> Result<usize, JsValue
     }
 }
 
+/// Row indices currently invalid for `col_idx`, from the cached invalid-row index
+/// (`validate_column`/`apply_suggestion`/`apply_correction` populate it; this scans fresh only if
+/// nothing's cached yet), so the UI can jump between errors without re-scanning the column.
+#[wasm_bindgen]
+pub fn get_invalid_rows(col_idx: usize) -> Result<Vec<usize>, JsValue> {
+    let mut store = DATASET.lock().unwrap();
+    if let Some(df) = store.as_mut() {
+        if col_idx >= df.columns.len() {
+            return Err(JsValue::from_str("Column index out of bounds"));
+        }
+        if let Some(cached) = df.invalid_rows(col_idx) {
+            return Ok(cached.clone());
+        }
+        let col_type = df.columns[col_idx].detected_type;
+        Ok(df.validate_column_fast(col_idx, col_type))
+    } else {
+        Err(JsValue::from_str("No dataset loaded"))
+    }
+}
+
 #[wasm_bindgen]
 pub fn validate_column(col_idx: usize, type_name: &str) -> Result<Vec<usize>, JsValue> {
     let mut store = DATASET.lock().unwrap();
@@ -383,6 +636,8 @@ pub fn validate_column(col_idx: usize, type_name: &str) -> Result<Vec<usize>, Js
             "Time" => ColumnType::Time,
             "Currency" => ColumnType::Currency,
             "Percentage" => ColumnType::Percentage,
+            "PhoneIntl" => ColumnType::PhoneIntl,
+            "Url" => ColumnType::Url,
             _ => return Err(JsValue::from_str(&format!("Unknown type: {}", type_name))),
         };
 
@@ -401,15 +656,35 @@ pub fn validate_column(col_idx: usize, type_name: &str) -> Result<Vec<usize>, Js
     }
 }
 
+/// Append a derived column to the dataset, evaluated lazily per row from `expr_json` (see
+/// `engine::expr::Expr`) inside `get_row`/export rather than materialized up front. Starts
+/// `Text`-typed like a freshly parsed column; call `validate_column` afterward to assign its real
+/// type (e.g. `Boolean` for a comparison predicate) and scan it for errors. Returns the new
+/// column's index.
+#[wasm_bindgen]
+pub fn add_computed_column(name: &str, expr_json: JsValue) -> Result<usize, JsValue> {
+    let expr: engine::expr::Expr = serde_wasm_bindgen::from_value(expr_json)
+        .map_err(|e| JsValue::from_str(&format!("Invalid expression: {}", e)))?;
+    let mut store = DATASET.lock().unwrap();
+    if let Some(df) = store.as_mut() {
+        df.add_computed_column(name.to_string(), ColumnType::Text, expr)
+            .map_err(|e| JsValue::from_str(&e))
+    } else {
+        Err(JsValue::from_str("No dataset loaded"))
+    }
+}
+
 #[wasm_bindgen]
 pub fn update_schema(schema_js: JsValue) -> Result<(), JsValue> {
     let schema: Vec<ColumnSchema> = serde_wasm_bindgen::from_value(schema_js)
         .map_err(|e| JsValue::from_str(&format!("Failed to deserialize schema: {}", e)))?;
-    
+    validate_computed_columns(&schema).map_err(|e| JsValue::from_str(&e))?;
+
     let mut store = DATASET.lock().unwrap();
     if let Some(df) = store.as_mut() {
         if df.columns.len() == schema.len() {
             df.columns = schema;
+            df.invalid_index.clear();
         } else {
             return Err(JsValue::from_str("Schema length mismatch"));
         }
@@ -422,8 +697,10 @@ pub fn update_cell(row_idx: usize, col_idx: usize, value: String) -> Result<(),
     let start = Instant::now();
     let mut store = DATASET.lock().unwrap();
     if let Some(df) = store.as_mut() {
+        let prior_val = df.patches.get(&row_idx).and_then(|m| m.get(&col_idx)).cloned();
         df.update_cell(row_idx, col_idx, value)
             .map_err(|e| JsValue::from_str(&e))?;
+        df.record_operation("update_cell", col_idx, vec![(row_idx, prior_val)]);
         let ms = start.elapsed().as_millis();
         log(&format!("[update_cell] row={} col={} ms={}", row_idx, col_idx, ms));
         Ok(())
@@ -432,9 +709,77 @@ pub fn update_cell(row_idx: usize, col_idx: usize, value: String) -> Result<(),
     }
 }
 
+/// Undo the most recently recorded mutation (see [`DataFrame::record_operation`]), restoring
+/// every cell it touched to its value beforehand. Returns `false` if there's nothing to undo.
+#[wasm_bindgen]
+pub fn undo() -> Result<bool, JsValue> {
+    let mut store = DATASET.lock().unwrap();
+    if let Some(df) = store.as_mut() {
+        Ok(df.undo())
+    } else {
+        Err(JsValue::from_str("No dataset loaded"))
+    }
+}
+
+/// Replay the most recently undone mutation. Returns `false` if there's nothing to redo.
+#[wasm_bindgen]
+pub fn redo() -> Result<bool, JsValue> {
+    let mut store = DATASET.lock().unwrap();
+    if let Some(df) = store.as_mut() {
+        Ok(df.redo())
+    } else {
+        Err(JsValue::from_str("No dataset loaded"))
+    }
+}
+
+/// Number of operations currently available to `undo()`.
+#[wasm_bindgen]
+pub fn history_len() -> Result<usize, JsValue> {
+    let store = DATASET.lock().unwrap();
+    if let Some(df) = &*store {
+        Ok(df.history_len())
+    } else {
+        Err(JsValue::from_str("No dataset loaded"))
+    }
+}
+
+/// Override the undo/redo ring buffer depth (default `DEFAULT_HISTORY_DEPTH`).
+#[wasm_bindgen]
+pub fn set_history_depth(depth: usize) -> Result<(), JsValue> {
+    let mut store = DATASET.lock().unwrap();
+    if let Some(df) = store.as_mut() {
+        df.set_history_depth(depth);
+        Ok(())
+    } else {
+        Err(JsValue::from_str("No dataset loaded"))
+    }
+}
+
+/// Materialize the patched dataset back out as `"csv"` or `"ndjson"`, walking every row through
+/// `DataFrame::get_row` (so patches are applied) and handing `chunk_fn` raw UTF-8 byte chunks of a
+/// few MB at a time, instead of building one giant `JsValue` that would OOM on a large export.
+/// `delimiter` is only used for `"csv"`. Returns the number of rows written.
+#[wasm_bindgen]
+pub fn export_dataset(format: &str, delimiter: u8, include_header: bool, chunk_fn: &js_sys::Function) -> Result<usize, JsValue> {
+    let store = DATASET.lock().unwrap();
+    if let Some(df) = &*store {
+        let start = Instant::now();
+        let mut on_chunk = |bytes: &[u8]| {
+            let array = js_sys::Uint8Array::from(bytes);
+            let _ = chunk_fn.call1(&JsValue::NULL, &array);
+        };
+        let rows = export::export_rows(df, format, delimiter, include_header, export::DEFAULT_CHUNK_BYTES, &mut on_chunk)
+            .map_err(|e| JsValue::from_str(&e))?;
+        let ms = start.elapsed().as_millis();
+        log(&format!("[export_dataset] format={} rows={} ms={}", format, rows, ms));
+        Ok(rows)
+    } else {
+        Err(JsValue::from_str("No dataset loaded"))
+    }
+}
+
 /// Process find/replace for a range of rows only. Used by the worker to chunk work and avoid
 /// long-running single calls that can hit "unreachable" (stack/timeout) on large datasets.
-/// Uses one CSV Reader per chunk (streaming), like validate_range, instead of one Reader per row.
 #[wasm_bindgen]
 pub fn find_replace_range(start_row: usize, row_limit: usize, find: &str, replace: &str) -> Result<u32, JsValue> {
     let mut store = DATASET.lock().unwrap();
@@ -489,3 +834,135 @@ fn find_replace_all_inner(find: &str, replace: &str) -> Result<u32, JsValue> {
         Err(JsValue::from_str("No dataset loaded"))
     }
 }
+
+/// Open a staged transaction and return its handle. Route `apply_suggestion_in_transaction`/
+/// `apply_bulk_action_in_transaction` calls through the handle to accumulate changes in a scratch
+/// patch map instead of `df.patches`; `preview_transaction` samples the diff, and
+/// `commit_transaction`/`abort_transaction` atomically merge it into the dataset or discard it.
+#[wasm_bindgen]
+pub fn begin_transaction() -> u64 {
+    let mut next = NEXT_TXN_HANDLE.lock().unwrap();
+    let handle = *next;
+    *next += 1;
+    TRANSACTIONS.lock().unwrap().insert(handle, Transaction::new());
+    handle
+}
+
+/// Like [`apply_suggestion`], but stages changes under `handle` (see [`begin_transaction`])
+/// instead of writing them to `df.patches`. Reads fall through any prior staged edit in the same
+/// transaction before the committed dataset, so chained calls see each other's changes.
+#[wasm_bindgen]
+pub fn apply_suggestion_in_transaction(handle: u64, col_idx: usize, suggestion_json: JsValue) -> Result<usize, JsValue> {
+    let suggestion: mechanic::Suggestion = serde_wasm_bindgen::from_value(suggestion_json)?;
+    let mut store = DATASET.lock().unwrap();
+    let mut txns = TRANSACTIONS.lock().unwrap();
+    let txn = txns.get_mut(&handle).ok_or_else(|| JsValue::from_str("Unknown transaction handle"))?;
+
+    if let Some(df) = store.as_mut() {
+        if col_idx >= df.columns.len() {
+            return Err(JsValue::from_str("Column index out of bounds"));
+        }
+        let col_type = df.columns[col_idx].detected_type;
+        let validity_gated = !mechanic::is_always_apply_normalizer(&suggestion) && !mechanic::is_redaction_suggestion(&suggestion);
+        let row_scan: Box<dyn Iterator<Item = usize>> = if validity_gated {
+            let invalid = df
+                .invalid_rows(col_idx)
+                .cloned()
+                .unwrap_or_else(|| df.validate_column_fast(col_idx, col_type));
+            Box::new(invalid.into_iter())
+        } else {
+            Box::new(0..df.rows)
+        };
+
+        let mut fixed_count = 0;
+        for row_idx in row_scan {
+            if let Some(old_val) = txn.get_cell(df, row_idx, col_idx) {
+                let (new_val, _is_redaction) = mechanic::compute_suggestion_value(&suggestion, &old_val);
+                if mechanic::should_apply_suggestion(&suggestion, &old_val, &new_val, col_type) {
+                    txn.set_cell(row_idx, col_idx, new_val);
+                    fixed_count += 1;
+                }
+            }
+        }
+        Ok(fixed_count)
+    } else {
+        Err(JsValue::from_str("No dataset loaded"))
+    }
+}
+
+/// Like [`apply_bulk_action`], but stages changes under `handle` (see [`begin_transaction`])
+/// instead of writing them to `df.patches`.
+#[wasm_bindgen]
+pub fn apply_bulk_action_in_transaction(handle: u64, col_idx: usize, action_json: JsValue) -> Result<JsValue, JsValue> {
+    let action: bulk::BulkAction = serde_wasm_bindgen::from_value(action_json)
+        .map_err(|e| JsValue::from_str(&format!("Invalid bulk action: {}", e)))?;
+    let compiled_regex = bulk::compile_regex_for_action(&action)
+        .map_err(|e| JsValue::from_str(&format!("Invalid regex: {}", e)))?;
+
+    let store = DATASET.lock().unwrap();
+    let mut txns = TRANSACTIONS.lock().unwrap();
+    let txn = txns.get_mut(&handle).ok_or_else(|| JsValue::from_str("Unknown transaction handle"))?;
+
+    if let Some(df) = &*store {
+        if col_idx >= df.columns.len() {
+            return Err(JsValue::from_str("Column index out of bounds"));
+        }
+        let mut changed_count = 0;
+        let mut replacement_count = 0;
+        for row_idx in 0..df.rows {
+            if let Some(old_val) = txn.get_cell(df, row_idx, col_idx) {
+                match bulk::apply_to_cell_counted(&old_val, &action, compiled_regex.as_ref()) {
+                    Ok((new_val, count)) => {
+                        replacement_count += count;
+                        if new_val != old_val {
+                            txn.set_cell(row_idx, col_idx, new_val);
+                            changed_count += 1;
+                        }
+                    }
+                    Err(e) => return Err(JsValue::from_str(&format!("Regex error: {}", e))),
+                }
+            }
+        }
+        Ok(serde_wasm_bindgen::to_value(&BulkActionResult { changed_cells: changed_count, replacements: replacement_count })?)
+    } else {
+        Err(JsValue::from_str("No dataset loaded"))
+    }
+}
+
+/// Sample up to `limit` `{row, col, old, new}` diffs staged under `handle`, plus the true total
+/// affected count, so the UI can show "this will change N cells" before committing to anything.
+#[wasm_bindgen]
+pub fn preview_transaction(handle: u64, limit: usize) -> Result<JsValue, JsValue> {
+    let store = DATASET.lock().unwrap();
+    let txns = TRANSACTIONS.lock().unwrap();
+    let txn = txns.get(&handle).ok_or_else(|| JsValue::from_str("Unknown transaction handle"))?;
+    if let Some(df) = &*store {
+        Ok(serde_wasm_bindgen::to_value(&txn.preview(df, limit))?)
+    } else {
+        Err(JsValue::from_str("No dataset loaded"))
+    }
+}
+
+/// Atomically merge every change staged under `handle` into the dataset's patches and retire the
+/// transaction. Returns the number of cells written. Errors leave the transaction open so the
+/// caller can retry or `abort_transaction` it.
+#[wasm_bindgen]
+pub fn commit_transaction(handle: u64) -> Result<usize, JsValue> {
+    let mut store = DATASET.lock().unwrap();
+    let mut txns = TRANSACTIONS.lock().unwrap();
+    if let Some(df) = store.as_mut() {
+        let txn = txns.remove(&handle).ok_or_else(|| JsValue::from_str("Unknown transaction handle"))?;
+        Ok(txn.commit(df, "transaction"))
+    } else {
+        Err(JsValue::from_str("No dataset loaded"))
+    }
+}
+
+/// Discard every change staged under `handle` without touching the dataset. Returns an error if
+/// the handle doesn't exist (already committed/aborted, or never opened).
+#[wasm_bindgen]
+pub fn abort_transaction(handle: u64) -> Result<(), JsValue> {
+    let mut txns = TRANSACTIONS.lock().unwrap();
+    txns.remove(&handle).ok_or_else(|| JsValue::from_str("Unknown transaction handle"))?;
+    Ok(())
+}